@@ -0,0 +1,423 @@
+//! Expands `#[contract(requires(..), ensures(..))]`.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{Expr, Item, ItemFn, Lifetime, Macro, Meta, ReturnType, Stmt, Token, Type};
+
+/// The standard macros which never return from the enclosing function.
+const NON_RETURNING_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "cfg",
+    "column",
+    "concat",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "env",
+    "eprint",
+    "eprintln",
+    "file",
+    "format",
+    "format_args",
+    "include",
+    "include_bytes",
+    "include_str",
+    "line",
+    "matches",
+    "module_path",
+    "option_env",
+    "panic",
+    "print",
+    "println",
+    "stringify",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+/// The conditions given to the attribute.
+pub(crate) struct Contract {
+    requires: Vec<Expr>,
+    ensures: Vec<Expr>,
+}
+
+impl Contract {
+    /// Parses the arguments of the attribute, e.g. `requires(x > 0), ensures(|ret| *ret > x)`.
+    pub(crate) fn parse(args: Punctuated<Meta, Token![,]>) -> syn::Result<Self> {
+        let mut contract = Contract {
+            requires: Vec::new(),
+            ensures: Vec::new(),
+        };
+        for meta in args {
+            let list = match meta {
+                Meta::List(ref list) => list,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "expected `requires(..)` or `ensures(..)`",
+                    ))
+                }
+            };
+            let conditions =
+                list.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+            if list.path.is_ident("requires") {
+                contract.requires.extend(conditions);
+            } else if list.path.is_ident("ensures") {
+                contract.ensures.extend(conditions);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &list.path,
+                    "expected `requires(..)` or `ensures(..)`",
+                ));
+            }
+        }
+        Ok(contract)
+    }
+}
+
+/// Expands the function with the preconditions checked at the entry
+/// and the postconditions checked on the return value.
+pub(crate) fn expand(contract: &Contract, mut item: ItemFn) -> syn::Result<TokenStream> {
+    if let Some(asyncness) = item.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "contract cannot be applied to async functions",
+        ));
+    }
+    if let (Some(constness), Some(condition)) = (item.sig.constness, contract.ensures.first()) {
+        let mut err = syn::Error::new_spanned(
+            condition,
+            "ensures cannot be applied to const functions, which cannot call the closures",
+        );
+        err.combine(syn::Error::new_spanned(constness, "the function is const"));
+        return Err(err);
+    }
+
+    let function = item.sig.ident.to_string();
+    let requires: Vec<_> = contract
+        .requires
+        .iter()
+        .map(|condition| {
+            // The failure handler cannot be called in const functions, so they panic directly.
+            let violated = if item.sig.constness.is_some() {
+                quote_spanned! {condition.span()=>
+                    ::core::panic!(::core::concat!(
+                        "Precondition \"",
+                        ::core::stringify!(#condition),
+                        "\" of ",
+                        #function,
+                        " violated",
+                    ))
+                }
+            } else {
+                quote_spanned! {condition.span()=>
+                    ::chain_assertions::__private::precondition_violated(
+                        #function,
+                        stringify!(#condition),
+                    )
+                }
+            };
+            quote_spanned! {condition.span()=>
+                if ::chain_assertions::__private::DEBUG_CHECK {
+                    let __holds: bool = #condition;
+                    if !__holds {
+                        #violated;
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let mut stmts = core::mem::take(&mut item.block.stmts);
+    let body = if contract.ensures.is_empty() {
+        quote! {
+            #(#requires)*
+            #(#stmts)*
+        }
+    } else {
+        let ensures = contract.ensures.iter().map(|condition| {
+            quote_spanned! {condition.span()=>
+                if ::chain_assertions::__private::DEBUG_CHECK {
+                    let __holds = ::chain_assertions::__private::holds_for(&__ret, #condition);
+                    if !__holds {
+                        ::chain_assertions::__private::postcondition_violated(
+                            #function,
+                            stringify!(#condition),
+                        );
+                    }
+                }
+            }
+        });
+
+        // The body stays inline so that it can borrow from the arguments and return
+        // `impl Trait`. `return` and `?` in it break out of the loop with the return value.
+        let mut route = RouteExits::default();
+        for stmt in &mut stmts {
+            route.visit_stmt_mut(stmt);
+        }
+        if let Some(err) = route.error {
+            return Err(err);
+        }
+        let warnings = route.warnings;
+        let label = label();
+        let tail = match stmts.last() {
+            Some(&Stmt::Expr(_, None)) => match stmts.pop() {
+                // A block right after the label would be confused with a labeled block.
+                Some(Stmt::Expr(expr @ Expr::Block(_), None)) => {
+                    quote!(#[allow(unreachable_code)] break #label (#expr);)
+                }
+                Some(Stmt::Expr(expr, None)) => {
+                    quote!(#[allow(unreachable_code)] break #label #expr;)
+                }
+                _ => unreachable!(),
+            },
+            Some(Stmt::Macro(mac)) if mac.semi_token.is_none() => match stmts.pop() {
+                Some(Stmt::Macro(mac)) => quote!(#[allow(unreachable_code)] break #label #mac;),
+                _ => unreachable!(),
+            },
+            _ if returns_unit(&item.sig.output) => {
+                quote!(#[allow(unreachable_code)] break #label ();)
+            }
+            // The body diverges without the tail expression.
+            _ => quote!(),
+        };
+        let annotation = match item.sig.output {
+            ReturnType::Type(_, ref ty) if !contains_impl_trait(ty) => quote!(: #ty),
+            _ => quote!(),
+        };
+        quote! {
+            #(#warnings)*
+            #(#requires)*
+            #[allow(clippy::never_loop)]
+            let __ret #annotation = #label: loop {
+                #(#stmts)*
+                #tail
+            };
+            #(#ensures)*
+            __ret
+        }
+    };
+
+    let ItemFn {
+        ref attrs,
+        ref vis,
+        ref sig,
+        ..
+    } = item;
+    Ok(quote!(#(#attrs)* #vis #sig { #body }))
+}
+
+/// Returns the label of the loop which the body breaks out of with the return value.
+fn label() -> Lifetime {
+    Lifetime::new("'__contract", Span::call_site())
+}
+
+/// Rewrites `return` and `?` in the body into `break` out of the labeled loop,
+/// leaving closures, async blocks and nested items as is.
+///
+/// The macro invocations cannot be rewritten, so `return` and `?` in their arguments are errors,
+/// and the macros other than [`NON_RETURNING_MACROS`] are warned since they may return.
+#[derive(Default)]
+struct RouteExits {
+    error: Option<syn::Error>,
+    warnings: Vec<TokenStream>,
+}
+
+impl RouteExits {
+    fn check_macro(&mut self, mac: &Macro) {
+        let name = match mac.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return,
+        };
+        if contains_exit(mac.tokens.clone()) {
+            let err = syn::Error::new_spanned(
+                mac,
+                format!(
+                    "`return` and `?` in `{}!` cannot be routed through the postconditions",
+                    name
+                ),
+            );
+            match self.error {
+                Some(ref mut error) => error.combine(err),
+                None => self.error = Some(err),
+            }
+        } else if !NON_RETURNING_MACROS.contains(&name.as_str()) {
+            // A proc macro cannot emit a warning on stable, so it uses a deprecated item instead.
+            let note = format!(
+                "`{}!` may return without checking the postconditions of the contract",
+                name
+            );
+            self.warnings.push(quote_spanned! {mac.path.span()=>
+                {
+                    #[deprecated(note = #note)]
+                    #[allow(non_camel_case_types)]
+                    struct __contract_macro_may_return;
+                    let _ = __contract_macro_may_return;
+                }
+            });
+        }
+    }
+}
+
+impl VisitMut for RouteExits {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match *expr {
+            Expr::Closure(_) | Expr::Async(_) | Expr::Const(_) => {}
+            Expr::Return(ref mut ret) => {
+                let label = label();
+                let span = ret.span();
+                let value = match ret.expr.take() {
+                    Some(mut value) => {
+                        self.visit_expr_mut(&mut value);
+                        quote!(#value)
+                    }
+                    None => quote!(()),
+                };
+                // syn cannot parse a labeled break with a value starting with `::`,
+                // so the rewritten expressions are kept as tokens.
+                *expr = Expr::Verbatim(quote_spanned! {span=> break #label #value });
+            }
+            Expr::Try(ref mut try_expr) => {
+                self.visit_expr_mut(&mut try_expr.expr);
+                let label = label();
+                let span = try_expr.question_token.span();
+                let operand = &try_expr.expr;
+                *expr = Expr::Verbatim(quote_spanned! {span=>
+                    (match ::chain_assertions::__private::Branch::branch(#operand) {
+                        ::core::ops::ControlFlow::Continue(__value) => __value,
+                        ::core::ops::ControlFlow::Break(__residual) => break #label
+                            ::chain_assertions::__private::FromResidual::from_residual(__residual),
+                    })
+                });
+            }
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        self.check_macro(mac);
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/// Returns `true` if the tokens contain `return` or `?`.
+fn contains_exit(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ref ident) => ident == "return",
+        TokenTree::Punct(ref punct) => punct.as_char() == '?',
+        TokenTree::Group(ref group) => contains_exit(group.stream()),
+        TokenTree::Literal(_) => false,
+    })
+}
+
+/// Returns `true` if the function returns `()`.
+fn returns_unit(output: &ReturnType) -> bool {
+    match *output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ref ty) => {
+            matches!(**ty, Type::Tuple(ref tuple) if tuple.elems.is_empty())
+        }
+    }
+}
+
+/// Returns `true` if the type contains `impl Trait`, which cannot annotate a variable.
+fn contains_impl_trait(ty: &Type) -> bool {
+    fn contains(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ref ident) => ident == "impl",
+            TokenTree::Group(ref group) => contains(group.stream()),
+            _ => false,
+        })
+    }
+    contains(ty.to_token_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    mod expand {
+        use super::super::*;
+        use syn::parse::Parser;
+
+        fn expand_with(args: TokenStream, item: ItemFn) -> syn::Result<String> {
+            let args = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+            expand(&Contract::parse(args)?, item).map(|tokens| tokens.to_string())
+        }
+
+        #[test]
+        fn it_accepts_standard_macros() {
+            let tokens = expand_with(
+                quote!(ensures(|ret| !ret.is_empty())),
+                syn::parse_quote! {
+                    fn singleton(x: u32) -> Vec<u32> {
+                        assert!(x > 0, "{:?}", x);
+                        vec![x]
+                    }
+                },
+            )
+            .unwrap();
+            assert!(!tokens.contains("deprecated"));
+        }
+
+        #[test]
+        fn it_warns_on_macro_which_may_return() {
+            let tokens = expand_with(
+                quote!(ensures(|ret| ret.is_ok())),
+                syn::parse_quote! {
+                    fn parse(text: &str) -> Result<u32, String> {
+                        if text.is_empty() {
+                            bail!("empty");
+                        }
+                        Ok(1)
+                    }
+                },
+            )
+            .unwrap();
+            assert!(tokens.contains(
+                "\"`bail!` may return without checking the postconditions of the contract\""
+            ));
+        }
+
+        #[test]
+        fn it_rejects_return_inside_macro() {
+            let err = expand_with(
+                quote!(ensures(|ret| *ret > 0)),
+                syn::parse_quote! {
+                    fn one(x: Option<u32>) -> u32 {
+                        println!("{:?}", x?);
+                        1
+                    }
+                },
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "`return` and `?` in `println!` cannot be routed through the postconditions"
+            );
+        }
+
+        #[test]
+        fn it_ignores_macros_without_ensures() {
+            let tokens = expand_with(
+                quote!(requires(x > 0)),
+                syn::parse_quote! {
+                    fn one(x: u32) -> u32 {
+                        ensure!(x < 10);
+                        1
+                    }
+                },
+            )
+            .unwrap();
+            assert!(!tokens.contains("deprecated"));
+        }
+    }
+}
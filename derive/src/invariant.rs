@@ -0,0 +1,86 @@
+//! Expands `#[derive(Invariant)]`.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Expr, Index, Member, Meta};
+
+/// Expands the derive input into an implementation of `Invariant`.
+///
+/// The conditions of `#[invariant(..)]` on the type are checked first in declaration order,
+/// and then the invariants of the fields marked with `#[invariant]`.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut checks = Vec::new();
+    for attr in invariant_attrs(&input.attrs) {
+        let condition: Expr = attr.parse_args()?;
+        checks.push(quote_spanned! {condition.span()=>
+            let __holds: bool = #condition;
+            if !__holds {
+                return ::core::result::Result::Err(
+                    ::chain_assertions::InvariantViolation::new(stringify!(#condition)),
+                );
+            }
+        });
+    }
+
+    match input.data {
+        Data::Struct(ref data) => {
+            for (i, field) in data.fields.iter().enumerate() {
+                for attr in invariant_attrs(&field.attrs) {
+                    if !matches!(attr.meta, Meta::Path(_)) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "expected `#[invariant]` without arguments on a field",
+                        ));
+                    }
+                    let member = match field.ident {
+                        Some(ref ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(Index::from(i)),
+                    };
+                    checks.push(quote_spanned! {field.span()=>
+                        ::chain_assertions::Invariant::check_invariants(&self.#member)?;
+                    });
+                }
+            }
+        }
+        Data::Enum(ref data) => {
+            let field = data
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter())
+                .find(|field| invariant_attrs(&field.attrs).next().is_some());
+            if let Some(field) = field {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`#[invariant]` on fields is only supported for structs",
+                ));
+            }
+        }
+        Data::Union(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Invariant cannot be derived for unions",
+            ));
+        }
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::chain_assertions::Invariant for #ty #ty_generics #where_clause {
+            fn check_invariants(
+                &self,
+            ) -> ::core::result::Result<(), ::chain_assertions::InvariantViolation> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+/// Returns the `#[invariant]` attributes.
+fn invariant_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("invariant"))
+}
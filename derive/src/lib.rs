@@ -0,0 +1,82 @@
+//! Derive and attribute macros for [chain-assertions](https://docs.rs/chain-assertions).
+//!
+//! Use them through the `derive` feature of `chain-assertions` instead of depending on this crate
+//! directly, since the generated code refers to `::chain_assertions`.
+
+use proc_macro::TokenStream;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, DeriveInput, ItemFn, Meta, Token};
+
+mod contract;
+mod invariant;
+mod variants;
+
+/// Generates the assertion methods for each variant of the enum.
+///
+/// For a variant `Foo`, the following inherent methods are generated:
+///
+/// - `assert_is_foo(self) -> Self`
+/// - `debug_assert_is_foo(self) -> Self`
+/// - `assert_is_foo_and(self, condition) -> Self` and `debug_assert_is_foo_and`
+///   if the variant has fields
+///
+/// The condition receives a reference to the field if the variant has a single field,
+/// or a tuple of references to the fields in declaration order otherwise.
+///
+/// It is an error if two variants generate the same method, e.g. `Foo(u32)` and `FooAnd`,
+/// or `HTTP` and `Http` which are both converted into `http`.
+///
+/// The enum must implement `chain_assertions::Printable` to print the value on failure.
+#[proc_macro_derive(ChainAssertVariants)]
+pub fn derive_chain_assert_variants(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variants::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `chain_assertions::Invariant` by the conditions given in the attributes.
+///
+/// - `#[invariant(condition)]` on the type adds the condition, where `self` refers to the value.
+///   It can be repeated, and the conditions are checked in declaration order.
+/// - `#[invariant]` on a field of a struct checks the invariants of the field,
+///   after the conditions on the type.
+///
+/// The violation describes the first condition not satisfied, or is passed through from the field.
+#[proc_macro_derive(Invariant, attributes(invariant))]
+pub fn derive_invariant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    invariant::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Checks the preconditions and postconditions of the function, i.e. design by contract.
+///
+/// - `requires(condition, ..)` checks the conditions at the entry of the function.
+/// - `ensures(|ret| condition, ..)` checks the closures with a reference to the return value
+///   when the function returns.
+///
+/// Like the `debug_assert_*` methods, the conditions are checked only if `debug_assertions`
+/// or the `always-check` feature is enabled, and the `passthrough` feature is disabled.
+/// The failure reports the location of the violated condition.
+///
+/// The body stays inline, and `return` and `?` on [`Result`] or [`Option`] in it are routed
+/// through the postconditions. Those inside macro invocations cannot be routed, so with `ensures`:
+///
+/// - `return` or `?` in the arguments of a macro is an error.
+/// - A macro other than the standard ones known not to return, e.g. `bail!`, is warned
+///   by the `deprecated` lint, since it may return without checking the postconditions.
+///
+/// `const fn` can have only `requires`, which panics directly instead of calling the failure
+/// handler, since it cannot be called in const context. Panicking in const context needs
+/// Rust 1.57, which is covered by the MSRV of this crate.
+#[proc_macro_attribute]
+pub fn contract(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with Punctuated::<Meta, Token![,]>::parse_terminated);
+    let item = parse_macro_input!(item as ItemFn);
+    contract::Contract::parse(args)
+        .and_then(|contract| contract::expand(&contract, item))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
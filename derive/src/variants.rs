@@ -0,0 +1,280 @@
+//! Expands `#[derive(ChainAssertVariants)]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields, Ident, Variant};
+
+/// Expands the derive input into an inherent impl block with the variant assertions.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ChainAssertVariants can only be derived for enums",
+            ))
+        }
+    };
+
+    check_method_names(data.variants.iter())?;
+
+    let ty = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let methods = data
+        .variants
+        .iter()
+        .map(|variant| expand_variant(vis, variant));
+
+    Ok(quote! {
+        #[allow(dead_code)]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Checks no two variants generate the methods with the same name,
+/// e.g. `Foo(..)` and `FooAnd` both generating `assert_is_foo_and`.
+fn check_method_names<'a>(variants: impl Iterator<Item = &'a Variant>) -> syn::Result<()> {
+    let mut names: Vec<(String, &Ident)> = Vec::new();
+    for variant in variants {
+        let name = to_snake_case(&variant_name(variant));
+        let mut methods = vec![format!("assert_is_{}", name)];
+        if !variant.fields.is_empty() {
+            methods.push(format!("assert_is_{}_and", name));
+        }
+        for method in methods {
+            if let Some((_, other)) = names.iter().find(|(name, _)| *name == method) {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "`{}` generates `{}`, which is also generated for `{}`",
+                        variant.ident, method, other
+                    ),
+                ));
+            }
+            names.push((method, &variant.ident));
+        }
+    }
+    Ok(())
+}
+
+/// Expands the assertion methods for the variant.
+fn expand_variant(vis: &syn::Visibility, variant: &Variant) -> TokenStream {
+    let ident = &variant.ident;
+    let unraw = variant_name(variant);
+    let name = to_snake_case(&unraw);
+    let assert_is = format_ident!("assert_is_{}", name);
+    let debug_assert_is = format_ident!("debug_assert_is_{}", name);
+
+    let (pattern, expected) = match variant.fields {
+        Fields::Named(_) => (quote!(Self::#ident { .. }), format!("{} {{ .. }}", unraw)),
+        Fields::Unnamed(_) => (quote!(Self::#ident(..)), format!("{}(..)", unraw)),
+        Fields::Unit => (quote!(Self::#ident), unraw),
+    };
+    let assert_doc = format!("Asserts the value is `{}`.", expected);
+    let debug_assert_doc = format!("Asserts the value is `{}` only in debug builds.", expected);
+
+    let mut tokens = quote! {
+        #[doc = #assert_doc]
+        #[track_caller]
+        #[inline]
+        #vis fn #assert_is(self) -> Self
+        where
+            Self: ::chain_assertions::Printable,
+        {
+            if !matches!(self, #pattern) {
+                ::chain_assertions::__private::unexpected_variant(#expected, &self);
+            }
+            self
+        }
+
+        #[doc = #debug_assert_doc]
+        #[track_caller]
+        #[inline]
+        #vis fn #debug_assert_is(self) -> Self
+        where
+            Self: ::chain_assertions::Printable,
+        {
+            if ::chain_assertions::__private::DEBUG_CHECK {
+                self.#assert_is()
+            } else {
+                self
+            }
+        }
+    };
+
+    if variant.fields.is_empty() {
+        return tokens;
+    }
+
+    let assert_is_and = format_ident!("assert_is_{}_and", name);
+    let debug_assert_is_and = format_ident!("debug_assert_is_{}_and", name);
+    let assert_and_doc = format!(
+        "Asserts the value is `{}` and the fields satisfy the condition.",
+        expected
+    );
+    let debug_assert_and_doc = format!(
+        "Asserts the value is `{}` and the fields satisfy the condition only in debug builds.",
+        expected
+    );
+
+    let bindings: Vec<Ident> = (0..variant.fields.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect();
+    let binding_pattern = match variant.fields {
+        Fields::Named(ref fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(Self::#ident { #(#names: ref #bindings),* })
+        }
+        _ => quote!(Self::#ident(#(ref #bindings),*)),
+    };
+    let types: Vec<_> = variant.fields.iter().map(|field| &field.ty).collect();
+    let (arg_type, arg) = if types.len() == 1 {
+        (quote!(&#(#types)*), quote!(#(#bindings)*))
+    } else {
+        (quote!((#(&#types),*)), quote!((#(#bindings),*)))
+    };
+
+    tokens.extend(quote! {
+        #[doc = #assert_and_doc]
+        #[track_caller]
+        #[inline]
+        #vis fn #assert_is_and<__F>(self, condition: __F) -> Self
+        where
+            Self: ::chain_assertions::Printable,
+            __F: FnOnce(#arg_type) -> bool,
+        {
+            match self {
+                #binding_pattern => {
+                    if !condition(#arg) {
+                        ::chain_assertions::__private::condition_not_satisfied(&self);
+                    }
+                }
+                _ => ::chain_assertions::__private::unexpected_variant(#expected, &self),
+            }
+            self
+        }
+
+        #[doc = #debug_assert_and_doc]
+        #[track_caller]
+        #[inline]
+        #vis fn #debug_assert_is_and<__F>(self, condition: __F) -> Self
+        where
+            Self: ::chain_assertions::Printable,
+            __F: FnOnce(#arg_type) -> bool,
+        {
+            if ::chain_assertions::__private::DEBUG_CHECK {
+                self.#assert_is_and(condition)
+            } else {
+                self
+            }
+        }
+    });
+    tokens
+}
+
+/// Returns the name of the variant without the `r#` prefix of a raw identifier,
+/// which is printed by `Debug` in the same way.
+fn variant_name(variant: &Variant) -> String {
+    variant.ident.unraw().to_string()
+}
+
+/// Converts the `UpperCamelCase` identifier into `snake_case`, e.g. `HTTPError` to `http_error`.
+fn to_snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut snake = String::with_capacity(ident.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = if i > 0 { chars.get(i - 1) } else { None };
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(&p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(&p) if p.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if boundary && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    mod expand {
+        use super::super::*;
+
+        #[test]
+        fn it_expands_distinct_variants() {
+            let input: DeriveInput = syn::parse_quote! {
+                enum State { Foo(u32), Bar, FooBar { x: u32 } }
+            };
+            assert!(expand(&input).is_ok());
+        }
+
+        #[test]
+        fn it_expands_raw_identifiers() {
+            let input: DeriveInput = syn::parse_quote! {
+                enum Keyword { r#Type, r#Match(u32) }
+            };
+            let tokens = expand(&input).unwrap().to_string();
+            assert!(tokens.contains("fn assert_is_type"));
+            assert!(tokens.contains("fn assert_is_match_and"));
+            assert!(tokens.contains("\"Match(..)\""));
+        }
+
+        #[test]
+        fn it_rejects_variant_colliding_with_and_method() {
+            let input: DeriveInput = syn::parse_quote! {
+                enum State { Foo(u32), FooAnd }
+            };
+            let err = expand(&input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "`FooAnd` generates `assert_is_foo_and`, which is also generated for `Foo`"
+            );
+        }
+
+        #[test]
+        fn it_rejects_variants_with_same_snake_case() {
+            let input: DeriveInput = syn::parse_quote! {
+                enum Protocol { HTTP, Http }
+            };
+            let err = expand(&input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "`Http` generates `assert_is_http`, which is also generated for `HTTP`"
+            );
+        }
+    }
+
+    mod to_snake_case {
+        use super::super::*;
+
+        #[test]
+        fn it_converts_camel_case() {
+            assert_eq!(to_snake_case("Alive"), "alive");
+            assert_eq!(to_snake_case("WaitingForPlayers"), "waiting_for_players");
+        }
+
+        #[test]
+        fn it_keeps_acronyms_together() {
+            assert_eq!(to_snake_case("HTTPError"), "http_error");
+            assert_eq!(to_snake_case("IOError"), "io_error");
+            assert_eq!(to_snake_case("Utf8"), "utf8");
+            assert_eq!(to_snake_case("V2Ray"), "v2_ray");
+        }
+
+        #[test]
+        fn it_keeps_underscores() {
+            assert_eq!(to_snake_case("Snake_Case"), "snake_case");
+        }
+    }
+}
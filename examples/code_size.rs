@@ -0,0 +1,37 @@
+//! Instantiates the assertions for many types.
+//!
+//! It is used to check that the failure paths are not duplicated for each instantiation:
+//!
+//! ```sh
+//! cargo build --release --example code_size
+//! nm -C target/release/examples/code_size | grep -c '::assert_ok::fail$' # => 1
+//! ```
+
+use chain_assertions::prelude::*;
+
+/// Hides the value from the optimizer so that every failure path remains.
+fn opaque<T: Copy>(x: T) -> T {
+    // SAFETY: `x` is a valid and aligned local variable.
+    unsafe { std::ptr::read_volatile(&x) }
+}
+
+macro_rules! instantiate {
+    ($($ty:ty),+) => {
+        $(
+            let x: Result<$ty, $ty> = if opaque(true) {
+                Ok(Default::default())
+            } else {
+                Err(Default::default())
+            };
+            println!("{:?}", x.assert_ok().assert_ok_and(|_| opaque(true)));
+
+            let x: Option<$ty> = if opaque(true) { None } else { Some(Default::default()) };
+            println!("{:?}", x.assert_none());
+        )+
+    };
+}
+
+fn main() {
+    instantiate!(u8, u16, u32, u64, i8, i16, i32, i64, bool, char);
+    instantiate!(String, Vec<u8>);
+}
@@ -0,0 +1,649 @@
+//! Assertions for byte buffers and pointers.
+//!
+//! On failure, the bytes around the offending offset are printed as a hex dump.
+
+use crate::preview::{AddressPreview, HexDump};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// An extension trait to add the byte buffer assertion methods.
+pub trait AssertBytesExt {
+    /// Asserts the bytes are valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// If the bytes are not valid UTF-8, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = b"hello";
+    /// let x = core::str::from_utf8(x.assert_utf8());
+    /// assert_eq!(x, Ok("hello"));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = b"hel\xfflo";
+    /// let _ = x.assert_utf8();
+    /// //        ^-- panics here
+    /// ```
+    fn assert_utf8(self) -> Self;
+
+    /// Asserts the bytes are valid UTF-8 only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The bytes are not valid UTF-8
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = b"hello";
+    /// let x = x.debug_assert_utf8().len();
+    /// assert_eq!(x, 5);
+    /// ```
+    fn debug_assert_utf8(self) -> Self;
+
+    /// Asserts the length of the bytes is a multiple of `n`.
+    ///
+    /// # Panics
+    ///
+    /// If the length is not a multiple of `n`, or `n` is zero and the bytes are not empty,
+    /// the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = &[0; 12];
+    /// let x = x.assert_len_multiple_of(4).chunks_exact(4).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = &[0; 10];
+    /// let _ = x.assert_len_multiple_of(4);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_len_multiple_of(self, n: usize) -> Self;
+
+    /// Asserts the length of the bytes is a multiple of `n` only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The length is not a multiple of `n`, or `n` is zero and the bytes are not empty
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![0u8; 12].debug_assert_len_multiple_of(4).len();
+    /// assert_eq!(x, 12);
+    /// ```
+    fn debug_assert_len_multiple_of(self, n: usize) -> Self;
+
+    /// Asserts the bytes start with the prefix, e.g. the magic number of a file format.
+    ///
+    /// # Panics
+    ///
+    /// If the bytes do not start with the prefix, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = b"\x89PNG\r\n\x1a\n...";
+    /// let x = x.assert_prefix(b"\x89PNG").len();
+    /// assert_eq!(x, 11);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[u8] = b"GIF89a...";
+    /// let _ = x.assert_prefix(b"\x89PNG");
+    /// //        ^-- panics here
+    /// ```
+    fn assert_prefix(self, prefix: &[u8]) -> Self;
+
+    /// Asserts the bytes start with the prefix only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The bytes do not start with the prefix
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = b"GIF89a...".to_vec().debug_assert_prefix(b"GIF").len();
+    /// assert_eq!(x, 9);
+    /// ```
+    fn debug_assert_prefix(self, prefix: &[u8]) -> Self;
+}
+
+/// An extension trait to add the assertion_aligned_to methods.
+pub trait AssertAlignedToExt {
+    /// Asserts the pointer, or the pointer to the first item, is aligned to `align` bytes.
+    ///
+    /// # Panics
+    ///
+    /// If the address is not a multiple of `align`, or `align` is zero, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [0u32; 4];
+    /// let x = (&x as *const [u32; 4]).assert_aligned_to(4);
+    /// assert_eq!(unsafe { *x }, [0; 4]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 0x1001 as *const u8;
+    /// let _ = x.assert_aligned_to(4);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_aligned_to(self, align: usize) -> Self;
+
+    /// Asserts the pointer, or the pointer to the first item, is aligned to `align` bytes
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The address is not a multiple of `align`, or `align` is zero
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![0u64; 4].debug_assert_aligned_to(8).len();
+    /// assert_eq!(x, 4);
+    /// ```
+    fn debug_assert_aligned_to(self, align: usize) -> Self;
+}
+
+#[track_caller]
+#[inline]
+fn check_utf8(bytes: &[u8]) {
+    if let Err(err) = core::str::from_utf8(bytes) {
+        let offset = err.valid_up_to();
+        let dump = HexDump { bytes, offset };
+        fail!(
+            InvalidUtf8,
+            "Expected valid UTF-8, got an invalid sequence at offset {:?}: {:?}",
+            offset,
+            dump
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_len_multiple_of(bytes: &[u8], n: usize) {
+    let len = bytes.len();
+    let rem = len.checked_rem(n).unwrap_or(len);
+    if rem != 0 {
+        let dump = HexDump {
+            bytes,
+            offset: len - rem,
+        };
+        fail!(
+            UnexpectedLength,
+            "Expected a length multiple of {:?}, got length {:?}: {:?}",
+            n,
+            len,
+            dump
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_prefix(bytes: &[u8], prefix: &[u8]) {
+    if !bytes.starts_with(prefix) {
+        let offset = bytes
+            .iter()
+            .zip(prefix)
+            .position(|(a, b)| a != b)
+            .unwrap_or(bytes.len());
+        let expected = HexDump {
+            bytes: prefix,
+            offset,
+        };
+        let actual = HexDump { bytes, offset };
+        fail!(
+            UnexpectedPrefix,
+            "Expected the prefix {:?}, got a mismatch at offset {:?}: {:?}",
+            expected,
+            offset,
+            actual
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_aligned_to(address: usize, align: usize) {
+    if address.checked_rem(align) != Some(0) {
+        let address = AddressPreview(address);
+        fail!(
+            Misaligned,
+            "Expected an address aligned to {:?}, got {:?}",
+            align,
+            address
+        );
+    }
+}
+
+macro_rules! impl_bytes_assertions {
+    ($([$($gen:tt)*] $ty:ty;)+) => {
+        $(
+            impl<$($gen)*> AssertBytesExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_utf8(self) -> Self {
+                    check_utf8(&self[..]);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_utf8(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_utf8(&self[..]);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_len_multiple_of(self, n: usize) -> Self {
+                    check_len_multiple_of(&self[..], n);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_len_multiple_of(self, _n: usize) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_len_multiple_of(&self[..], _n);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_prefix(self, prefix: &[u8]) -> Self {
+                    check_prefix(&self[..], prefix);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_prefix(self, _prefix: &[u8]) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_prefix(&self[..], _prefix);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_aligned_to_assertions {
+    ($([$($gen:tt)*] $ty:ty => |$this:ident| $address:expr;)+) => {
+        $(
+            impl<$($gen)*> AssertAlignedToExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_aligned_to(self, align: usize) -> Self {
+                    check_aligned_to({ let $this = &self; $address }, align);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_aligned_to(self, _align: usize) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_aligned_to({ let $this = &self; $address }, _align);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_bytes_assertions! {
+    ['a] &'a [u8];
+}
+
+#[cfg(feature = "alloc")]
+impl_bytes_assertions! {
+    [] Vec<u8>;
+    ['a] &'a Vec<u8>;
+}
+
+impl_aligned_to_assertions! {
+    ['a, T] &'a [T] => |x| x.as_ptr() as usize;
+    [T: ?Sized] *const T => |x| *x as *const u8 as usize;
+    [T: ?Sized] *mut T => |x| *x as *const u8 as usize;
+}
+
+#[cfg(feature = "alloc")]
+impl_aligned_to_assertions! {
+    [T] Vec<T> => |x| x.as_ptr() as usize;
+    ['a, T] &'a Vec<T> => |x| x.as_ptr() as usize;
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_utf8 {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_valid_utf8() {
+            let x: &[u8] = "héllo".as_bytes();
+            let x = x.assert_utf8().len();
+            assert_eq!(x, 6);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected valid UTF-8, got an invalid sequence at offset 3: \
+                                   68 65 6c [ff] 6c 6f"
+        )]
+        fn it_fails_on_invalid_byte() {
+            let x: &[u8] = b"hel\xfflo";
+            let _ = x.assert_utf8();
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected valid UTF-8, got an invalid sequence at offset 10: \
+                                   .. 32 33 34 35 36 37 38 39 [c3]"
+        )]
+        fn it_fails_on_truncated_sequence() {
+            let x: &[u8] = b"0123456789\xc3";
+            let _ = x.assert_utf8();
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_utf8 {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_valid_utf8() {
+            let x: &[u8] = b"hello";
+            let x = x.debug_assert_utf8();
+            assert_eq!(x, b"hello");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected valid UTF-8, got an invalid sequence at offset 0: [80]"
+            )
+        )]
+        fn it_fails_on_invalid_byte() {
+            let x: &[u8] = b"\x80";
+            let x = x.debug_assert_utf8();
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, b"\x80");
+        }
+    }
+
+    mod assert_len_multiple_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_multiple() {
+            let x: &[u8] = &[0; 12];
+            let x = x.assert_len_multiple_of(4).len();
+            assert_eq!(x, 12);
+        }
+
+        #[test]
+        fn it_succeeds_on_empty_bytes_with_zero() {
+            let x: &[u8] = &[];
+            let x = x.assert_len_multiple_of(0);
+            assert!(x.is_empty());
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a length multiple of 4, got length 22: \
+                                   .. 0c 0d 0e 0f 10 11 12 13 [14] 15")]
+        fn it_fails_on_trailing_bytes() {
+            let x: &[u8] = &[
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            ];
+            let _ = x.assert_len_multiple_of(4);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a length multiple of 0, got length 1: [00]")]
+        fn it_fails_on_zero() {
+            let x: &[u8] = &[0];
+            let _ = x.assert_len_multiple_of(0);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_len_multiple_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_multiple() {
+            let x: &[u8] = &[0; 8];
+            let x = x.debug_assert_len_multiple_of(8);
+            assert_eq!(x, &[0; 8]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a length multiple of 2, got length 3: 01 02 [03]")
+        )]
+        fn it_fails_on_trailing_byte() {
+            let x: &[u8] = &[1, 2, 3];
+            let x = x.debug_assert_len_multiple_of(2);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &[1, 2, 3]);
+        }
+    }
+
+    mod assert_prefix {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_prefix() {
+            let x: &[u8] = b"\x89PNG\r\n\x1a\n";
+            let x = x.assert_prefix(b"\x89PNG").len();
+            assert_eq!(x, 8);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected the prefix [89] 50 4e 47, got a mismatch at offset 0: \
+                                   [47] 49 46 38 39 61"
+        )]
+        fn it_fails_on_different_prefix() {
+            let x: &[u8] = b"GIF89a";
+            let _ = x.assert_prefix(b"\x89PNG");
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected the prefix 89 50 [4e] 47, got a mismatch at offset 2: \
+                                   89 50 [end]"
+        )]
+        fn it_fails_on_short_bytes() {
+            let x: &[u8] = b"\x89P";
+            let _ = x.assert_prefix(b"\x89PNG");
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_prefix {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_prefix() {
+            let x: &[u8] = b"GIF89a";
+            let x = x.debug_assert_prefix(b"GIF");
+            assert_eq!(x, b"GIF89a");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected the prefix 47 49 [46], got a mismatch at offset 2: \
+                                     47 49 [47]"
+            )
+        )]
+        fn it_fails_on_different_prefix() {
+            let x: &[u8] = b"GIG";
+            let x = x.debug_assert_prefix(b"GIF");
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, b"GIG");
+        }
+    }
+
+    mod assert_aligned_to {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_aligned_slice() {
+            let x: &[u64] = &[1, 2];
+            let x = x.assert_aligned_to(8).len();
+            assert_eq!(x, 2);
+        }
+
+        #[test]
+        fn it_succeeds_on_aligned_pointer() {
+            let x = 42u32;
+            let x = (&x as *const u32).assert_aligned_to(4);
+            assert_eq!(unsafe { *x }, 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected an address aligned to 16, got 0x1004")]
+        fn it_fails_on_misaligned_pointer() {
+            let _ = (0x1004 as *const u8).assert_aligned_to(16);
+            //                            ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected an address aligned to 0, got 0x1000")]
+        fn it_fails_on_zero() {
+            let _ = (0x1000 as *mut u8).assert_aligned_to(0);
+            //                          ^-- should panic here
+        }
+    }
+
+    mod debug_assert_aligned_to {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_aligned_pointer() {
+            let x = 0x1000 as *const [u8; 4];
+            let x = x.debug_assert_aligned_to(4096);
+            assert_eq!(x as usize, 0x1000);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected an address aligned to 2, got 0x1001")
+        )]
+        fn it_fails_on_misaligned_pointer() {
+            let x = (0x1001 as *const u16).debug_assert_aligned_to(2);
+            //                             ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x as usize, 0x1001);
+        }
+    }
+}
@@ -0,0 +1,448 @@
+//! Assertions that a region of code does not modify a value.
+//!
+//! Use them where a `&mut` is passed into a callback which is supposed to be read-only.
+//! Take a [`Checkpoint`] by cloning the value, or a [`HashCheckpoint`] by hashing it
+//! when cloning is expensive, and compare the value with it after the region:
+//!
+//! ```rust
+//! use chain_assertions::prelude::*;
+//!
+//! let mut scores = [10, 20, 30];
+//! let checkpoint = scores.debug_checkpoint();
+//! scores.sort();
+//! checkpoint.assert_unchanged(&scores);
+//! ```
+//!
+//! The checkpoints hold nothing and compare nothing unless the `debug_assert_*` methods check,
+//! i.e. `debug_assertions` or `always-check` feature is enabled and `passthrough` feature is disabled.
+
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+/// An extension trait to add the assertion_unchanged_by methods.
+pub trait AssertUnchangedByExt: Sized {
+    /// Runs the function with the mutable reference to the value and asserts it did not modify the value.
+    ///
+    /// # Panics
+    ///
+    /// If the function modified the value, the method panics with both of the values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let mut total = 0;
+    /// let x = [1, 2, 3].assert_unchanged_by(|x| total = x.iter().sum());
+    /// assert_eq!((x, total), ([1, 2, 3], 6));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [3, 1, 2].assert_unchanged_by(|x| x.sort());
+    /// //                ^-- panics here
+    /// ```
+    fn assert_unchanged_by<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self);
+
+    /// Runs the function with the mutable reference to the value and asserts it did not modify
+    /// the value only in debug builds.
+    ///
+    /// The function always runs, but the value is cloned and compared only when it is checked.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The function modified the value
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns the value given to the function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![1, 2, 3].debug_assert_unchanged_by(|x| println!("{:?}", x));
+    /// assert_eq!(x, [1, 2, 3]);
+    /// ```
+    fn debug_assert_unchanged_by<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self);
+}
+
+#[track_caller]
+#[inline]
+fn check_unchanged<T>(before: &T, after: &T)
+where
+    T: ?Sized + PartialEq + crate::Printable,
+{
+    if *after != *before {
+        fail!(Changed, "Expected {:?} unchanged, got {:?}", before, after);
+    }
+}
+
+impl<T> AssertUnchangedByExt for T
+where
+    T: Clone + PartialEq + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_unchanged_by<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        let before = self.clone();
+        f(&mut self);
+        check_unchanged(&before, &self);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_unchanged_by<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            let before = self.clone();
+            f(&mut self);
+            check_unchanged(&before, &self);
+        }
+        #[cfg(not(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        )))]
+        {
+            f(&mut self);
+        }
+        self
+    }
+}
+
+/// A snapshot of a value taken by [`DebugCheckpointExt::debug_checkpoint`].
+pub struct Checkpoint<T> {
+    #[cfg(all(
+        any(debug_assertions, feature = "always-check"),
+        not(feature = "passthrough")
+    ))]
+    snapshot: T,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T> Checkpoint<T>
+where
+    T: PartialEq + crate::Printable,
+{
+    /// Asserts the value equals the snapshot only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value differs from the snapshot
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let mut name = String::from("alice");
+    /// let checkpoint = name.debug_checkpoint();
+    /// name.make_ascii_lowercase();
+    /// checkpoint.assert_unchanged(&name);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn assert_unchanged(&self, _value: &T) {
+        let _ = self.marker;
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_unchanged(&self.snapshot, _value);
+        }
+    }
+}
+
+/// A hash of a value taken by [`DebugCheckpointExt::debug_hash_checkpoint`].
+pub struct HashCheckpoint<T: ?Sized> {
+    #[cfg(all(
+        any(debug_assertions, feature = "always-check"),
+        not(feature = "passthrough")
+    ))]
+    hash: u64,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T> HashCheckpoint<T>
+where
+    T: ?Sized + Hash + crate::Printable,
+{
+    /// Asserts the hash of the value equals the one taken at the checkpoint only in debug builds.
+    ///
+    /// Unlike [`Checkpoint`], the modification is not detected if the hashes collide.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The hash of the value differs
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let mut tiles = vec![0u8; 1024];
+    /// let checkpoint = tiles.debug_hash_checkpoint();
+    /// tiles.iter_mut().for_each(|t| *t = t.saturating_sub(1));
+    /// checkpoint.assert_unchanged(&tiles);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn assert_unchanged(&self, _value: &T) {
+        let _ = self.marker;
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if hash_of(_value) != self.hash {
+                let value = _value;
+                fail!(
+                    Changed,
+                    "Expected the value unchanged since the checkpoint, got {:?} with a different hash",
+                    value
+                );
+            }
+        }
+    }
+}
+
+/// An extension trait to add the debug_checkpoint methods.
+pub trait DebugCheckpointExt {
+    /// Takes a snapshot of the value by cloning it only in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let mut scores = vec![10, 20, 30];
+    /// let checkpoint = scores.debug_checkpoint();
+    /// scores.dedup();
+    /// checkpoint.assert_unchanged(&scores);
+    /// ```
+    fn debug_checkpoint(&self) -> Checkpoint<Self>
+    where
+        Self: Clone;
+
+    /// Takes a hash of the value only in debug builds.
+    ///
+    /// The hash is computed by FNV-1a, so it is available without `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let name = "alice";
+    /// let checkpoint = name.debug_hash_checkpoint();
+    /// checkpoint.assert_unchanged(name);
+    /// ```
+    fn debug_hash_checkpoint(&self) -> HashCheckpoint<Self>
+    where
+        Self: Hash;
+}
+
+impl<T: ?Sized> DebugCheckpointExt for T {
+    #[inline]
+    fn debug_checkpoint(&self) -> Checkpoint<Self>
+    where
+        Self: Clone,
+    {
+        Checkpoint {
+            #[cfg(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ))]
+            snapshot: self.clone(),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn debug_hash_checkpoint(&self) -> HashCheckpoint<Self>
+    where
+        Self: Hash,
+    {
+        HashCheckpoint {
+            #[cfg(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ))]
+            hash: hash_of(self),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Hashes the value by FNV-1a.
+#[cfg(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+))]
+fn hash_of<T: ?Sized + Hash>(value: &T) -> u64 {
+    let mut hasher = FnvHasher(0xcbf2_9ce4_8422_2325);
+    value.hash(&mut hasher);
+    core::hash::Hasher::finish(&hasher)
+}
+
+/// The 64-bit FNV-1a hasher.
+#[cfg(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+))]
+struct FnvHasher(u64);
+
+#[cfg(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+))]
+impl core::hash::Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_unchanged_by {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_read_only_function() {
+            let mut total = 0;
+            let x = [1, 2, 3].assert_unchanged_by(|x| total = x.iter().sum());
+            assert_eq!((x, total), ([1, 2, 3], 6));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected [3, 1, 2] unchanged, got [1, 2, 3]")]
+        fn it_fails_on_modification() {
+            let _ = [3, 1, 2].assert_unchanged_by(|x| x.sort());
+            //                ^-- should panic here
+        }
+    }
+
+    mod debug_assert_unchanged_by {
+        use super::super::*;
+
+        #[test]
+        fn it_always_runs_function() {
+            let mut called = false;
+            let x = 42.debug_assert_unchanged_by(|_| called = true);
+            assert_eq!(x, 42);
+            assert!(called, "Expected function called");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected 42 unchanged, got 43")
+        )]
+        fn it_fails_on_modification() {
+            let x = 42.debug_assert_unchanged_by(|x| *x += 1);
+            //         ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 43);
+        }
+    }
+
+    mod checkpoint {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unchanged_value() {
+            let mut scores = [10, 20, 30];
+            let checkpoint = scores.debug_checkpoint();
+            scores.sort_unstable();
+            checkpoint.assert_unchanged(&scores);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected [30, 10, 20] unchanged, got [10, 20, 30]")
+        )]
+        fn it_fails_on_modification() {
+            let mut scores = [30, 10, 20];
+            let checkpoint = scores.debug_checkpoint();
+            scores.sort_unstable();
+            checkpoint.assert_unchanged(&scores);
+            //         ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(scores, [10, 20, 30]);
+        }
+    }
+
+    mod hash_checkpoint {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unchanged_value() {
+            let name = "alice";
+            let checkpoint = name.debug_hash_checkpoint();
+            checkpoint.assert_unchanged(name);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected the value unchanged since the checkpoint, \
+                                     got [0, 0, 1] with a different hash")
+        )]
+        fn it_fails_on_modification() {
+            let mut tiles = [0u8; 3];
+            let checkpoint = tiles.debug_hash_checkpoint();
+            tiles[2] = 1;
+            checkpoint.assert_unchanged(&tiles);
+            //         ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(tiles, [0, 0, 1]);
+        }
+    }
+}
@@ -0,0 +1,910 @@
+//! Assertions for comparable values.
+//!
+//! [`AssertCrossCheckExt`] compares the value with the one recomputed by a reference
+//! implementation, e.g. an obviously correct but slow algorithm replaced by a fast one.
+
+/// An extension trait to add the assertion_comparison methods.
+pub trait AssertCmpExt: Sized {
+    /// Asserts the value is less than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not less than the bound or not comparable, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.assert_lt(&100) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 100.assert_lt(&100) * 2;
+    /// //          ^-- panics here
+    /// ```
+    fn assert_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is less than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value is not less than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.debug_assert_lt(&100) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    fn debug_assert_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is less than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not less than or equal to the bound or not comparable, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 100.assert_le(&100) * 2;
+    /// assert_eq!(x, 200);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 101.assert_le(&100) * 2;
+    /// //          ^-- panics here
+    /// ```
+    fn assert_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is less than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value is not less than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 100.debug_assert_le(&100) * 2;
+    /// assert_eq!(x, 200);
+    /// ```
+    fn debug_assert_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is greater than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not greater than the bound or not comparable, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.assert_gt(&0) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 0.assert_gt(&0) * 2;
+    /// //        ^-- panics here
+    /// ```
+    fn assert_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is greater than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value is not greater than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.debug_assert_gt(&0) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    fn debug_assert_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is greater than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not greater than or equal to the bound or not comparable, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 0.assert_ge(&0) * 2;
+    /// assert_eq!(x, 0);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = (-1).assert_ge(&0) * 2;
+    /// //           ^-- panics here
+    /// ```
+    fn assert_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is greater than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value is not greater than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 0.debug_assert_ge(&0) * 2;
+    /// assert_eq!(x, 0);
+    /// ```
+    fn debug_assert_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is between `lo` and `hi`, both inclusive.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not between `lo` and `hi` or not comparable, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.assert_between(&0, &100) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 101.assert_between(&0, &100) * 2;
+    /// //          ^-- panics here
+    /// ```
+    fn assert_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+
+    /// Asserts the value is between `lo` and `hi`, both inclusive only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value is not between `lo` and `hi`
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.debug_assert_between(&0, &100) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    fn debug_assert_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>;
+}
+
+/// An extension trait to add the cross_check methods.
+pub trait AssertCrossCheckExt: Sized {
+    /// Asserts the value equals the one computed by the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// If the values differ, the method panics with both of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// fn fast_sum(n: u64) -> u64 {
+    ///     n * (n + 1) / 2
+    /// }
+    ///
+    /// let x = fast_sum(100).cross_check(|_| (1..=100).sum::<u64>());
+    /// assert_eq!(x, 5050);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// fn wrong_sum(n: u64) -> u64 {
+    ///     n * n / 2
+    /// }
+    ///
+    /// let _ = wrong_sum(100).cross_check(|_| (1..=100).sum::<u64>());
+    /// //                     ^-- panics here
+    /// ```
+    fn cross_check<R, F>(self, reference: F) -> Self
+    where
+        R: crate::Printable,
+        F: FnOnce(&Self) -> R,
+        Self: PartialEq<R>;
+
+    /// Asserts the value equals the one computed by the reference implementation
+    /// only in debug builds.
+    ///
+    /// The reference implementation is called only when the value is checked.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The values differ
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let mut items = vec![3, 1, 2];
+    /// items.sort_unstable();
+    /// let items = items.debug_cross_check(|items| {
+    ///     let mut sorted = items.clone();
+    ///     sorted.sort();
+    ///     sorted
+    /// });
+    /// assert_eq!(items, [1, 2, 3]);
+    /// ```
+    fn debug_cross_check<R, F>(self, reference: F) -> Self
+    where
+        R: crate::Printable,
+        F: FnOnce(&Self) -> R,
+        Self: PartialEq<R>;
+}
+
+impl<T> AssertCmpExt for T
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        if !(self < *bound) {
+            let value = &self;
+            fail!(
+                ComparisonFailed,
+                "Expected a value less than {:?}, got {:?}",
+                bound,
+                value
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_lt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if !(self < *_bound) {
+                let value = &self;
+                fail!(
+                    ComparisonFailed,
+                    "Expected a value less than {:?}, got {:?}",
+                    _bound,
+                    value
+                );
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        if !(self <= *bound) {
+            let value = &self;
+            fail!(
+                ComparisonFailed,
+                "Expected a value less than or equal to {:?}, got {:?}",
+                bound,
+                value
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_le<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if !(self <= *_bound) {
+                let value = &self;
+                fail!(
+                    ComparisonFailed,
+                    "Expected a value less than or equal to {:?}, got {:?}",
+                    _bound,
+                    value
+                );
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        if !(self > *bound) {
+            let value = &self;
+            fail!(
+                ComparisonFailed,
+                "Expected a value greater than {:?}, got {:?}",
+                bound,
+                value
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_gt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if !(self > *_bound) {
+                let value = &self;
+                fail!(
+                    ComparisonFailed,
+                    "Expected a value greater than {:?}, got {:?}",
+                    _bound,
+                    value
+                );
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        if !(self >= *bound) {
+            let value = &self;
+            fail!(
+                ComparisonFailed,
+                "Expected a value greater than or equal to {:?}, got {:?}",
+                bound,
+                value
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ge<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if !(self >= *_bound) {
+                let value = &self;
+                fail!(
+                    ComparisonFailed,
+                    "Expected a value greater than or equal to {:?}, got {:?}",
+                    _bound,
+                    value
+                );
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        if !(self >= *lo && self <= *hi) {
+            let value = &self;
+            fail!(
+                OutOfRange,
+                "Expected a value between {:?} and {:?}, got {:?}",
+                lo,
+                hi,
+                value
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_between<B>(self, _lo: &B, _hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        Self: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if !(self >= *_lo && self <= *_hi) {
+                let value = &self;
+                fail!(
+                    OutOfRange,
+                    "Expected a value between {:?} and {:?}, got {:?}",
+                    _lo,
+                    _hi,
+                    value
+                );
+            }
+        }
+        self
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_cross_check<T, R>(value: &T, expected: R)
+where
+    T: crate::Printable + PartialEq<R>,
+    R: crate::Printable,
+{
+    if *value != expected {
+        fail!(
+            CrossCheckFailed,
+            "Expected {:?} computed by the reference, got {:?}",
+            expected,
+            value
+        );
+    }
+}
+
+impl<T> AssertCrossCheckExt for T
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn cross_check<R, F>(self, reference: F) -> Self
+    where
+        R: crate::Printable,
+        F: FnOnce(&Self) -> R,
+        Self: PartialEq<R>,
+    {
+        check_cross_check(&self, reference(&self));
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_cross_check<R, F>(self, _reference: F) -> Self
+    where
+        R: crate::Printable,
+        F: FnOnce(&Self) -> R,
+        Self: PartialEq<R>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_cross_check(&self, _reference(&self));
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Version(u32, u32);
+
+    mod assert_lt {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_less() {
+            let x = 21.assert_lt(&100) * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        fn it_succeeds_on_str() {
+            let x = "abc".assert_lt(&"abd");
+            assert_eq!(x, "abc");
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value less than Version(1, 0), got Version(1, 2)")]
+        fn it_fails_on_greater() {
+            let _ = Version(1, 2).assert_lt(&Version(1, 0));
+            //                    ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value less than 1.0, got NaN")]
+        fn it_fails_on_incomparable() {
+            let _ = f64::NAN.assert_lt(&1.0);
+            //               ^-- should panic here
+        }
+    }
+
+    mod debug_assert_lt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_less() {
+            let x = 21.debug_assert_lt(&100) * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value less than 100, got 100")
+        )]
+        fn it_fails_on_equal() {
+            let x = 100.debug_assert_lt(&100);
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 100);
+        }
+    }
+
+    mod assert_le {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_equal() {
+            let x = Version(1, 0).assert_le(&Version(1, 0));
+            assert_eq!(x, Version(1, 0));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value less than or equal to 100, got 101")]
+        fn it_fails_on_greater() {
+            let _ = 101.assert_le(&100);
+            //          ^-- should panic here
+        }
+    }
+
+    mod debug_assert_le {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_equal() {
+            let x = 100.debug_assert_le(&100);
+            assert_eq!(x, 100);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value less than or equal to 'a', got 'b'")
+        )]
+        fn it_fails_on_greater() {
+            let x = 'b'.debug_assert_le(&'a');
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 'b');
+        }
+    }
+
+    mod assert_gt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_greater() {
+            let x = 21.assert_gt(&0) * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value greater than 0, got 0")]
+        fn it_fails_on_equal() {
+            let _ = 0u8.assert_gt(&0);
+            //          ^-- should panic here
+        }
+    }
+
+    mod debug_assert_gt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_greater() {
+            let x = 0.5.debug_assert_gt(&0.0);
+            assert_eq!(x, 0.5);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value greater than \"b\", got \"a\"")
+        )]
+        fn it_fails_on_less() {
+            let x = "a".debug_assert_gt(&"b");
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "a");
+        }
+    }
+
+    mod assert_ge {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_equal() {
+            let x = 0.assert_ge(&0);
+            assert_eq!(x, 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value greater than or equal to 0, got -1")]
+        fn it_fails_on_less() {
+            let _ = (-1).assert_ge(&0);
+            //           ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ge {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_greater() {
+            let x = 1i64.debug_assert_ge(&0);
+            assert_eq!(x, 1);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value greater than or equal to 0, got -1")
+        )]
+        fn it_fails_on_less() {
+            let x = (-1i64).debug_assert_ge(&0);
+            //              ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, -1);
+        }
+    }
+
+    mod assert_between {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_bounds() {
+            let lo = 0.assert_between(&0, &100);
+            let hi = 100.assert_between(&0, &100);
+            assert_eq!((lo, hi), (0, 100));
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a value between Version(1, 0) and Version(2, 0), got Version(2, 1)"
+        )]
+        fn it_fails_out_of_bounds() {
+            let _ = Version(2, 1).assert_between(&Version(1, 0), &Version(2, 0));
+            //                    ^-- should panic here
+        }
+    }
+
+    mod debug_assert_between {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_between_bounds() {
+            let x = 21.debug_assert_between(&0, &100) * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value between 0 and 100, got 101")
+        )]
+        fn it_fails_out_of_bounds() {
+            let x = 101.debug_assert_between(&0, &100);
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 101);
+        }
+    }
+
+    mod cross_check {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_same_value() {
+            let x = Version(1, 2).cross_check(|v| Version(v.0, 2));
+            assert_eq!(x, Version(1, 2));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 5050 computed by the reference, got 5000")]
+        fn it_fails_on_different_value() {
+            let _ = (100u64 * 100 / 2).cross_check(|_| (1..=100).sum::<u64>());
+            //                         ^-- should panic here
+        }
+    }
+
+    mod debug_cross_check {
+        use super::super::*;
+
+        fn sorted(items: &[i32; 3]) -> [i32; 3] {
+            let mut sorted = *items;
+            sorted.sort();
+            sorted
+        }
+
+        #[test]
+        fn it_calls_reference_only_in_debug_builds() {
+            let mut called = false;
+            let x = 42.debug_cross_check(|x| {
+                called = true;
+                *x
+            });
+            assert_eq!(x, 42);
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(called, checked);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected [1, 2, 3] computed by the reference, got [3, 1, 2]")
+        )]
+        fn it_fails_on_different_value() {
+            let x = [3, 1, 2].debug_cross_check(sorted);
+            //                ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [3, 1, 2]);
+        }
+    }
+}
@@ -0,0 +1,2215 @@
+//! Assertions for collections.
+//!
+//! The assertions are implemented for slices and string slices, and for their owned versions
+//! and `BTreeMap`/`BTreeSet` when the `alloc` feature is enabled,
+//! and for `HashMap`/`HashSet` when the `std` feature is enabled.
+//! The uniqueness and permutation assertions are also available for iterators
+//! by collecting them into a `Vec` with `AssertCollectExt` when the `alloc` feature is enabled.
+//! They compare the items by `PartialEq` in O(n²) time in every configuration,
+//! and their `_hashed` versions count `Eq + Hash` items in a hash map when the `std` feature is enabled.
+//! On failure, the size and the first entries of the collection are printed.
+
+use crate::fmt;
+#[cfg(feature = "alloc")]
+use crate::preview::MapPreview;
+use crate::preview::{ListPreview, StrPreview};
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+/// An extension trait to add the assertion_non_empty methods.
+pub trait AssertNonEmptyExt {
+    /// Asserts the collection is not empty.
+    ///
+    /// # Panics
+    ///
+    /// If the collection is empty, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![1, 2, 3].assert_non_empty().len();
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "".assert_non_empty();
+    /// //         ^-- panics here
+    /// ```
+    fn assert_non_empty(self) -> Self;
+
+    /// Asserts the collection is not empty only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The collection is empty
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "42".debug_assert_non_empty().parse::<i32>();
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_non_empty(self) -> Self;
+}
+
+/// An extension trait to add the assertion_len methods.
+pub trait AssertLenExt {
+    /// Asserts the collection has the length.
+    ///
+    /// The length of a string is counted in bytes, as [`str::len`] does.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the collection differs, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[1, 2, 3];
+    /// let x = x.assert_len(3).iter().sum::<i32>();
+    /// assert_eq!(x, 6);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![1, 2, 3].assert_len(2);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_len(self, len: usize) -> Self;
+
+    /// Asserts the collection has the length only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The length of the collection differs
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![1, 2, 3].debug_assert_len(3).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_len(self, len: usize) -> Self;
+}
+
+/// An extension trait to add the assertion_contains methods.
+pub trait AssertContainsExt<T> {
+    /// Asserts the collection contains the item.
+    ///
+    /// # Panics
+    ///
+    /// If the collection does not contain the item, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![1, 2, 3].assert_contains(&2).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![1, 2, 3].assert_contains(&4);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_contains(self, item: &T) -> Self;
+
+    /// Asserts the collection contains the item only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The collection does not contain the item
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[1, 2, 3];
+    /// let x = x.debug_assert_contains(&2).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_contains(self, item: &T) -> Self;
+}
+
+/// An extension trait to add the assertion_contains_key methods.
+#[cfg(feature = "alloc")]
+pub trait AssertContainsKeyExt<K> {
+    /// Asserts the map contains the key.
+    ///
+    /// # Panics
+    ///
+    /// If the map does not contain the key, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let x = map.assert_contains_key(&1)[&1];
+    /// assert_eq!(x, "a");
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let _ = map.assert_contains_key(&3);
+    /// //          ^-- panics here
+    /// ```
+    fn assert_contains_key(self, key: &K) -> Self;
+
+    /// Asserts the map contains the key only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The map does not contain the key
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let x = map.debug_assert_contains_key(&2)[&2];
+    /// assert_eq!(x, "b");
+    /// ```
+    fn debug_assert_contains_key(self, key: &K) -> Self;
+}
+
+/// An extension trait to add the assertion_not_contains_key methods.
+#[cfg(feature = "alloc")]
+pub trait AssertNotContainsKeyExt<K> {
+    /// Asserts the map does not contain the key.
+    ///
+    /// # Panics
+    ///
+    /// If the map contains the key, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let x = map.assert_not_contains_key(&3).len();
+    /// assert_eq!(x, 2);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let _ = map.assert_not_contains_key(&2);
+    /// //          ^-- panics here
+    /// ```
+    fn assert_not_contains_key(self, key: &K) -> Self;
+
+    /// Asserts the map does not contain the key only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The map contains the key
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+    /// let x = map.debug_assert_not_contains_key(&3).len();
+    /// assert_eq!(x, 2);
+    /// ```
+    fn debug_assert_not_contains_key(self, key: &K) -> Self;
+}
+
+/// An extension trait to add the assertion_unique methods.
+pub trait AssertUniqueExt {
+    /// Asserts the items are unique.
+    ///
+    /// The items are compared in O(n²) time. Use
+    /// [`assert_unique_hashed`](AssertUniqueHashedExt::assert_unique_hashed) for large collections
+    /// of hashable items.
+    ///
+    /// # Panics
+    ///
+    /// If any item equals to another one, the method panics with the first duplicate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![3, 1, 2].assert_unique().len();
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![3, 1, 3].assert_unique();
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_unique(self) -> Self;
+
+    /// Asserts the items are unique only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any item equals to another one
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[3, 1, 2];
+    /// let x = x.debug_assert_unique().len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_unique(self) -> Self;
+}
+
+/// An extension trait to add the assertion_unique_by_key methods.
+pub trait AssertUniqueByKeyExt<T> {
+    /// Asserts the keys of the items are unique.
+    ///
+    /// The keys are compared in O(n²) time and the key function is called O(n²) times.
+    ///
+    /// # Panics
+    ///
+    /// If any key equals to another one, the method panics with the first duplicate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![(1, "a"), (2, "a")].assert_unique_by_key(|x| x.0).len();
+    /// assert_eq!(x, 2);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![(1, "a"), (2, "a")].assert_unique_by_key(|x| x.1);
+    /// //                               ^-- panics here
+    /// ```
+    fn assert_unique_by_key<K, F>(self, f: F) -> Self
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&T) -> K;
+
+    /// Asserts the keys of the items are unique only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any key equals to another one
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![(1, "a"), (2, "a")].debug_assert_unique_by_key(|x| x.0).len();
+    /// assert_eq!(x, 2);
+    /// ```
+    fn debug_assert_unique_by_key<K, F>(self, f: F) -> Self
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&T) -> K;
+}
+
+/// An extension trait to add the assertion_permutation_of methods.
+pub trait AssertPermutationOfExt<T> {
+    /// Asserts the items are a permutation of the other ones,
+    /// i.e. they are equal as multisets.
+    ///
+    /// The items are compared in O(n²) time. Use
+    /// [`assert_permutation_of_hashed`](AssertPermutationOfHashedExt::assert_permutation_of_hashed)
+    /// for large collections of hashable items.
+    ///
+    /// # Panics
+    ///
+    /// If the items are not a permutation of the other ones, the method panics with
+    /// the missing and unexpected items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let input = [3, 1, 2, 1];
+    /// let mut x = input.to_vec();
+    /// x.sort();
+    /// let x = x.assert_permutation_of(&input);
+    /// assert_eq!(x, [1, 1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let input = [3, 1, 2, 1];
+    /// let _ = vec![1, 2, 3].assert_permutation_of(&input);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_permutation_of(self, other: &[T]) -> Self;
+
+    /// Asserts the items are a permutation of the other ones only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The items are not a permutation of the other ones
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[1, 2, 3];
+    /// let x = x.debug_assert_permutation_of(&[3, 2, 1]).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_permutation_of(self, other: &[T]) -> Self;
+}
+
+/// An extension trait to add the assertion_unique_hashed methods.
+#[cfg(feature = "std")]
+pub trait AssertUniqueHashedExt {
+    /// Asserts the items are unique by counting them in a hash map.
+    ///
+    /// Unlike [`assert_unique`](AssertUniqueExt::assert_unique), the items are checked
+    /// in O(n) time, but they need to implement `Eq + Hash`.
+    ///
+    /// # Panics
+    ///
+    /// If any item equals to another one, the method panics with the first duplicate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![3, 1, 2].assert_unique_hashed().len();
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![3, 1, 3].assert_unique_hashed();
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_unique_hashed(self) -> Self;
+
+    /// Asserts the items are unique by counting them in a hash map only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any item equals to another one
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[3, 1, 2];
+    /// let x = x.debug_assert_unique_hashed().len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_unique_hashed(self) -> Self;
+}
+
+/// An extension trait to add the assertion_permutation_of_hashed methods.
+#[cfg(feature = "std")]
+pub trait AssertPermutationOfHashedExt<T> {
+    /// Asserts the items are a permutation of the other ones by counting them in a hash map.
+    ///
+    /// Unlike [`assert_permutation_of`](AssertPermutationOfExt::assert_permutation_of),
+    /// the items are checked in O(n) time, but they need to implement `Eq + Hash`.
+    ///
+    /// # Panics
+    ///
+    /// If the items are not a permutation of the other ones, the method panics with
+    /// the missing and unexpected items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![1, 1, 2, 3].assert_permutation_of_hashed(&[3, 1, 2, 1]);
+    /// assert_eq!(x, [1, 1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = vec![1, 2, 3].assert_permutation_of_hashed(&[3, 1, 2, 1]);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_permutation_of_hashed(self, other: &[T]) -> Self;
+
+    /// Asserts the items are a permutation of the other ones by counting them in a hash map
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The items are not a permutation of the other ones
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: &[i32] = &[1, 2, 3];
+    /// let x = x.debug_assert_permutation_of_hashed(&[3, 2, 1]).len();
+    /// assert_eq!(x, 3);
+    /// ```
+    fn debug_assert_permutation_of_hashed(self, other: &[T]) -> Self;
+}
+
+/// An extension trait to add the collect_unique and collect_permutation_of methods to iterators.
+///
+/// The items are collected into a [`Vec`] and checked in the same way as
+/// [`AssertUniqueExt`], [`AssertUniqueByKeyExt`] and [`AssertPermutationOfExt`].
+#[cfg(feature = "alloc")]
+pub trait AssertCollectExt: Iterator + Sized {
+    /// Collects the items into a [`Vec`] and asserts they are unique.
+    ///
+    /// # Panics
+    ///
+    /// If any item equals to another one, the method panics with the first duplicate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "a,b,c".split(',').collect_unique();
+    /// assert_eq!(x, ["a", "b", "c"]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "a,b,a".split(',').collect_unique();
+    /// //                         ^-- panics here
+    /// ```
+    fn collect_unique(self) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable;
+
+    /// Collects the items into a [`Vec`] and asserts they are unique only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any item equals to another one
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns the collected items as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = (1..4).map(|x| x * 2).debug_collect_unique();
+    /// assert_eq!(x, [2, 4, 6]);
+    /// ```
+    fn debug_collect_unique(self) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable;
+
+    /// Collects the items into a [`Vec`] and asserts the keys of them are unique.
+    ///
+    /// # Panics
+    ///
+    /// If any key equals to another one, the method panics with the first duplicate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [(1, "a"), (2, "a")].into_iter().collect_unique_by_key(|x| x.0);
+    /// assert_eq!(x.len(), 2);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [(1, "a"), (2, "a")].into_iter().collect_unique_by_key(|x| x.1);
+    /// //                                       ^-- panics here
+    /// ```
+    fn collect_unique_by_key<K, F>(self, f: F) -> Vec<Self::Item>
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&Self::Item) -> K;
+
+    /// Collects the items into a [`Vec`] and asserts the keys of them are unique
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any key equals to another one
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns the collected items as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [(1, "a"), (2, "a")].into_iter().debug_collect_unique_by_key(|x| x.0);
+    /// assert_eq!(x.len(), 2);
+    /// ```
+    fn debug_collect_unique_by_key<K, F>(self, f: F) -> Vec<Self::Item>
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&Self::Item) -> K;
+
+    /// Collects the items into a [`Vec`] and asserts they are a permutation of the other ones.
+    ///
+    /// # Panics
+    ///
+    /// If the items are not a permutation of the other ones, the method panics with
+    /// the missing and unexpected items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let input = [3, 1, 2];
+    /// let x = input.iter().map(|x| x * 2).collect_permutation_of(&[2, 4, 6]);
+    /// assert_eq!(x, [6, 2, 4]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = (1..3).collect_permutation_of(&[1, 2, 3]);
+    /// //             ^-- panics here
+    /// ```
+    fn collect_permutation_of(self, other: &[Self::Item]) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable;
+
+    /// Collects the items into a [`Vec`] and asserts they are a permutation of the other ones
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The items are not a permutation of the other ones
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns the collected items as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = (1..4).rev().debug_collect_permutation_of(&[1, 2, 3]);
+    /// assert_eq!(x, [3, 2, 1]);
+    /// ```
+    fn debug_collect_permutation_of(self, other: &[Self::Item]) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable;
+}
+
+/// A collection whose length and first entries can be printed on failure.
+trait Collection {
+    fn len(&self) -> usize;
+
+    fn fmt_preview(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<C> Collection for &C
+where
+    C: Collection + ?Sized,
+{
+    fn len(&self) -> usize {
+        C::len(self)
+    }
+
+    fn fmt_preview(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        C::fmt_preview(self, f)
+    }
+}
+
+/// Prints the first entries of the collection.
+struct Preview<'a, C: ?Sized>(&'a C);
+
+impl<C> fmt::Debug for Preview<'_, C>
+where
+    C: Collection + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_preview(f)
+    }
+}
+
+macro_rules! impl_collection {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bound:tt)*])? => |$c:ident| $preview:expr;)+) => {
+        $(
+            impl<$($gen)*> Collection for $ty $(where $($bound)*)? {
+                fn len(&self) -> usize {
+                    <$ty>::len(self)
+                }
+
+                fn fmt_preview(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let $c = self;
+                    fmt::Debug::fmt(&$preview, f)
+                }
+            }
+        )+
+    };
+}
+
+impl_collection! {
+    [T] [T] where [T: crate::Printable] => |c| ListPreview(c.iter());
+    [] str => |c| StrPreview(c);
+}
+
+#[cfg(feature = "alloc")]
+impl_collection! {
+    [T] Vec<T> where [T: crate::Printable] => |c| ListPreview(c.iter());
+    [] String => |c| StrPreview(c);
+    [T] BTreeSet<T> where [T: crate::Printable] => |c| ListPreview(c.iter());
+    [K, V] BTreeMap<K, V> where [K: crate::Printable, V: crate::Printable] => |c| MapPreview(c.iter());
+}
+
+#[cfg(feature = "std")]
+impl_collection! {
+    [T, S] HashSet<T, S> where [T: crate::Printable] => |c| ListPreview(c.iter());
+    [K, V, S] HashMap<K, V, S> where [K: crate::Printable, V: crate::Printable] => |c| MapPreview(c.iter());
+}
+
+#[track_caller]
+#[inline]
+fn check_non_empty<C>(collection: &C)
+where
+    C: Collection + ?Sized,
+{
+    if collection.len() == 0 {
+        let contents = Preview(collection);
+        fail!(Empty, "Expected a non-empty collection, got {:?}", contents);
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_len<C>(collection: &C, expected: usize)
+where
+    C: Collection + ?Sized,
+{
+    let len = collection.len();
+    if len != expected {
+        let contents = Preview(collection);
+        fail!(
+            UnexpectedLength,
+            "Expected a collection of length {:?}, got length {:?}: {:?}",
+            expected,
+            len,
+            contents
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_contains<C, T>(contained: bool, collection: &C, item: &T)
+where
+    C: Collection + ?Sized,
+    T: crate::Printable + ?Sized,
+{
+    if !contained {
+        let (len, contents) = (collection.len(), Preview(collection));
+        fail!(
+            NotContained,
+            "Expected a collection containing {:?}, got length {:?}: {:?}",
+            item,
+            len,
+            contents
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[track_caller]
+#[inline]
+fn check_contains_key<C, K>(contained: bool, map: &C, key: &K)
+where
+    C: Collection + ?Sized,
+    K: crate::Printable + ?Sized,
+{
+    if !contained {
+        let (len, contents) = (map.len(), Preview(map));
+        fail!(
+            MissingKey,
+            "Expected a map containing the key {:?}, got length {:?}: {:?}",
+            key,
+            len,
+            contents
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[track_caller]
+#[inline]
+fn check_not_contains_key<C, K>(contained: bool, map: &C, key: &K)
+where
+    C: Collection + ?Sized,
+    K: crate::Printable + ?Sized,
+{
+    if contained {
+        let (len, contents) = (map.len(), Preview(map));
+        fail!(
+            UnexpectedKey,
+            "Expected a map not containing the key {:?}, got length {:?}: {:?}",
+            key,
+            len,
+            contents
+        );
+    }
+}
+
+macro_rules! impl_len_assertions {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bound:tt)*])?;)+) => {
+        $(
+            impl<$($gen)*> AssertNonEmptyExt for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_non_empty(self) -> Self {
+                    check_non_empty(&self);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_non_empty(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_non_empty(&self);
+                    }
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertLenExt for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_len(self, len: usize) -> Self {
+                    check_len(&self, len);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_len(self, _len: usize) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_len(&self, _len);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_contains_assertions {
+    ($([$($gen:tt)*] $ty:ty => $item:ty $(where [$($bound:tt)*])?;)+) => {
+        $(
+            impl<$($gen)*> AssertContainsExt<$item> for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_contains(self, item: &$item) -> Self {
+                    check_contains(self.contains(item), &self, item);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_contains(self, _item: &$item) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_contains(self.contains(_item), &self, _item);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_contains_key_assertions {
+    ($([$($gen:tt)*] $ty:ty => $key:ty $(where [$($bound:tt)*])?;)+) => {
+        $(
+            impl<$($gen)*> AssertContainsKeyExt<$key> for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_contains_key(self, key: &$key) -> Self {
+                    check_contains_key(self.contains_key(key), &self, key);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_contains_key(self, _key: &$key) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_contains_key(self.contains_key(_key), &self, _key);
+                    }
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertNotContainsKeyExt<$key> for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_not_contains_key(self, key: &$key) -> Self {
+                    check_not_contains_key(self.contains_key(key), &self, key);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_not_contains_key(self, _key: &$key) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_not_contains_key(self.contains_key(_key), &self, _key);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_len_assertions! {
+    ['a, T] &'a [T] where [T: crate::Printable];
+    ['a] &'a str;
+}
+
+impl_contains_assertions! {
+    ['a, T] &'a [T] => T where [T: PartialEq + crate::Printable];
+}
+
+#[cfg(feature = "alloc")]
+impl_len_assertions! {
+    [T] Vec<T> where [T: crate::Printable];
+    ['a, T] &'a Vec<T> where [T: crate::Printable];
+    [] String;
+    ['a] &'a String;
+    [T] BTreeSet<T> where [T: crate::Printable];
+    ['a, T] &'a BTreeSet<T> where [T: crate::Printable];
+    [K, V] BTreeMap<K, V> where [K: crate::Printable, V: crate::Printable];
+    ['a, K, V] &'a BTreeMap<K, V> where [K: crate::Printable, V: crate::Printable];
+}
+
+#[cfg(feature = "alloc")]
+impl_contains_assertions! {
+    [T] Vec<T> => T where [T: PartialEq + crate::Printable];
+    ['a, T] &'a Vec<T> => T where [T: PartialEq + crate::Printable];
+    [T] BTreeSet<T> => T where [T: Ord + crate::Printable];
+    ['a, T] &'a BTreeSet<T> => T where [T: Ord + crate::Printable];
+}
+
+#[cfg(feature = "alloc")]
+impl_contains_key_assertions! {
+    [K, V] BTreeMap<K, V> => K where [K: Ord + crate::Printable, V: crate::Printable];
+    ['a, K, V] &'a BTreeMap<K, V> => K where [K: Ord + crate::Printable, V: crate::Printable];
+}
+
+#[cfg(feature = "std")]
+impl_len_assertions! {
+    [T, S] HashSet<T, S> where [T: crate::Printable];
+    ['a, T, S] &'a HashSet<T, S> where [T: crate::Printable];
+    [K, V, S] HashMap<K, V, S> where [K: crate::Printable, V: crate::Printable];
+    ['a, K, V, S] &'a HashMap<K, V, S> where [K: crate::Printable, V: crate::Printable];
+}
+
+#[cfg(feature = "std")]
+impl_contains_assertions! {
+    [T, S] HashSet<T, S> => T where [T: Eq + Hash + crate::Printable, S: BuildHasher];
+    ['a, T, S] &'a HashSet<T, S> => T where [T: Eq + Hash + crate::Printable, S: BuildHasher];
+}
+
+#[cfg(feature = "std")]
+impl_contains_key_assertions! {
+    [K, V, S] HashMap<K, V, S> => K
+        where [K: Eq + Hash + crate::Printable, V: crate::Printable, S: BuildHasher];
+    ['a, K, V, S] &'a HashMap<K, V, S> => K
+        where [K: Eq + Hash + crate::Printable, V: crate::Printable, S: BuildHasher];
+}
+
+/// Returns the indices of the first duplicate pair of the keys.
+fn find_duplicate<'a, T, K, F>(items: &'a [T], mut key: F) -> Option<(usize, usize)>
+where
+    K: PartialEq,
+    F: FnMut(&'a T) -> K,
+{
+    for (j, b) in items.iter().enumerate() {
+        let b = key(b);
+        for (i, a) in items[..j].iter().enumerate() {
+            if key(a) == b {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+/// Returns `true` if the items are equal to the other ones as multisets.
+fn is_permutation<T>(items: &[T], other: &[T]) -> bool
+where
+    T: PartialEq,
+{
+    items.len() == other.len() && items.iter().all(|x| count(items, x) == count(other, x))
+}
+
+/// Returns the indices of the first duplicate pair of the items by counting them in a hash map.
+#[cfg(feature = "std")]
+fn find_duplicate_hashed<T>(items: &[T]) -> Option<(usize, usize)>
+where
+    T: Eq + Hash,
+{
+    let mut seen = HashMap::with_capacity(items.len());
+    for (j, item) in items.iter().enumerate() {
+        match seen.entry(item) {
+            Entry::Occupied(entry) => return Some((*entry.get(), j)),
+            Entry::Vacant(entry) => {
+                entry.insert(j);
+            }
+        }
+    }
+    None
+}
+
+/// Returns `true` if the items are equal to the other ones as multisets
+/// by counting them in a hash map.
+#[cfg(feature = "std")]
+fn is_permutation_hashed<T>(items: &[T], other: &[T]) -> bool
+where
+    T: Eq + Hash,
+{
+    if items.len() != other.len() {
+        return false;
+    }
+    let mut counts = HashMap::with_capacity(items.len());
+    for item in items {
+        *counts.entry(item).or_insert(0isize) += 1;
+    }
+    for item in other {
+        *counts.entry(item).or_insert(0isize) -= 1;
+    }
+    counts.values().all(|&count| count == 0)
+}
+
+fn count<T: PartialEq>(items: &[T], item: &T) -> usize {
+    items.iter().filter(|x| *x == item).count()
+}
+
+/// Returns the items remaining after removing the other ones as multisets.
+fn surplus<'a, T>(items: &'a [T], other: &'a [T]) -> impl Iterator<Item = &'a T> + Clone + 'a
+where
+    T: PartialEq,
+{
+    items
+        .iter()
+        .enumerate()
+        .filter(move |&(i, x)| !items[..i].contains(x))
+        .flat_map(move |(_, x)| {
+            let n = count(items, x).saturating_sub(count(other, x));
+            core::iter::repeat(x).take(n)
+        })
+}
+
+#[track_caller]
+#[inline]
+fn check_unique<T>(items: &[T])
+where
+    T: PartialEq + crate::Printable,
+{
+    if let Some((i, j)) = find_duplicate(items, |x| x) {
+        duplicate_found(items, i, j);
+    }
+}
+
+#[cfg(feature = "std")]
+#[track_caller]
+#[inline]
+fn check_unique_hashed<T>(items: &[T])
+where
+    T: Eq + Hash + crate::Printable,
+{
+    if let Some((i, j)) = find_duplicate_hashed(items) {
+        duplicate_found(items, i, j);
+    }
+}
+
+#[track_caller]
+#[inline]
+fn duplicate_found<T>(items: &[T], i: usize, j: usize) -> !
+where
+    T: crate::Printable,
+{
+    let item = &items[j];
+    fail!(
+        Duplicate,
+        "Expected unique items, got {:?} at index {:?} and {:?}",
+        item,
+        i,
+        j
+    );
+}
+
+#[track_caller]
+#[inline]
+fn check_unique_by_key<T, K, F>(items: &[T], mut f: F)
+where
+    K: PartialEq + crate::Printable,
+    F: FnMut(&T) -> K,
+{
+    if let Some((i, j)) = find_duplicate(items, &mut f) {
+        let key = f(&items[j]);
+        fail!(
+            Duplicate,
+            "Expected unique keys, got {:?} at index {:?} and {:?}",
+            key,
+            i,
+            j
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_permutation<T>(items: &[T], other: &[T])
+where
+    T: PartialEq + crate::Printable,
+{
+    if !is_permutation(items, other) {
+        not_permutation(items, other);
+    }
+}
+
+#[cfg(feature = "std")]
+#[track_caller]
+#[inline]
+fn check_permutation_hashed<T>(items: &[T], other: &[T])
+where
+    T: Eq + Hash + crate::Printable,
+{
+    if !is_permutation_hashed(items, other) {
+        not_permutation(items, other);
+    }
+}
+
+#[track_caller]
+#[inline]
+fn not_permutation<T>(items: &[T], other: &[T]) -> !
+where
+    T: PartialEq + crate::Printable,
+{
+    let (expected, actual) = (ListPreview(other.iter()), ListPreview(items.iter()));
+    let missing = ListPreview(surplus(other, items));
+    let unexpected = ListPreview(surplus(items, other));
+    fail!(
+        NotPermutation,
+        "Expected a permutation of {:?}, got {:?}: missing {:?}, unexpected {:?}",
+        expected,
+        actual,
+        missing,
+        unexpected
+    );
+}
+
+macro_rules! impl_slice_assertions {
+    ($([$($gen:tt)*] $ty:ty => $item:ty;)+) => {
+        $(
+            impl<$($gen)*> AssertUniqueExt for $ty
+            where
+                $item: PartialEq + crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_unique(self) -> Self {
+                    check_unique(&self[..]);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_unique(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_unique(&self[..]);
+                    }
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertUniqueByKeyExt<$item> for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_unique_by_key<K, F>(self, f: F) -> Self
+                where
+                    K: PartialEq + crate::Printable,
+                    F: FnMut(&$item) -> K,
+                {
+                    check_unique_by_key(&self[..], f);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_unique_by_key<K, F>(self, _f: F) -> Self
+                where
+                    K: PartialEq + crate::Printable,
+                    F: FnMut(&$item) -> K,
+                {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_unique_by_key(&self[..], _f);
+                    }
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertPermutationOfExt<$item> for $ty
+            where
+                $item: PartialEq + crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_permutation_of(self, other: &[$item]) -> Self {
+                    check_permutation(&self[..], other);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_permutation_of(self, _other: &[$item]) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_permutation(&self[..], _other);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_slice_assertions! {
+    ['a, T] &'a [T] => T;
+}
+
+#[cfg(feature = "alloc")]
+impl_slice_assertions! {
+    [T] Vec<T> => T;
+    ['a, T] &'a Vec<T> => T;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_hashed_slice_assertions {
+    ($([$($gen:tt)*] $ty:ty => $item:ty;)+) => {
+        $(
+            impl<$($gen)*> AssertUniqueHashedExt for $ty
+            where
+                $item: Eq + Hash + crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_unique_hashed(self) -> Self {
+                    check_unique_hashed(&self[..]);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_unique_hashed(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_unique_hashed(&self[..]);
+                    }
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertPermutationOfHashedExt<$item> for $ty
+            where
+                $item: Eq + Hash + crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_permutation_of_hashed(self, other: &[$item]) -> Self {
+                    check_permutation_hashed(&self[..], other);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_permutation_of_hashed(self, _other: &[$item]) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_permutation_hashed(&self[..], _other);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+impl_hashed_slice_assertions! {
+    ['a, T] &'a [T] => T;
+    [T] Vec<T> => T;
+    ['a, T] &'a Vec<T> => T;
+}
+
+#[cfg(feature = "alloc")]
+impl<I> AssertCollectExt for I
+where
+    I: Iterator,
+{
+    #[track_caller]
+    #[inline]
+    fn collect_unique(self) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable,
+    {
+        let items: Vec<_> = self.collect();
+        check_unique(&items);
+        items
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_collect_unique(self) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable,
+    {
+        let items: Vec<_> = self.collect();
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_unique(&items);
+        }
+        items
+    }
+
+    #[track_caller]
+    #[inline]
+    fn collect_unique_by_key<K, F>(self, f: F) -> Vec<Self::Item>
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let items: Vec<_> = self.collect();
+        check_unique_by_key(&items, f);
+        items
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_collect_unique_by_key<K, F>(self, _f: F) -> Vec<Self::Item>
+    where
+        K: PartialEq + crate::Printable,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let items: Vec<_> = self.collect();
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_unique_by_key(&items, _f);
+        }
+        items
+    }
+
+    #[track_caller]
+    #[inline]
+    fn collect_permutation_of(self, other: &[Self::Item]) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable,
+    {
+        let items: Vec<_> = self.collect();
+        check_permutation(&items, other);
+        items
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_collect_permutation_of(self, _other: &[Self::Item]) -> Vec<Self::Item>
+    where
+        Self::Item: PartialEq + crate::Printable,
+    {
+        let items: Vec<_> = self.collect();
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_permutation(&items, _other);
+        }
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_non_empty {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_non_empty_slice() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.assert_non_empty().len();
+            assert_eq!(x, 3);
+        }
+
+        #[test]
+        fn it_succeeds_on_non_empty_str() {
+            let x = "42".assert_non_empty().parse::<i32>();
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a non-empty collection, got []")]
+        fn it_fails_on_empty_slice() {
+            let x: &[i32] = &[];
+            let _ = x.assert_non_empty();
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a non-empty collection, got \"\"")]
+        fn it_fails_on_empty_str() {
+            let _ = "".assert_non_empty();
+            //         ^-- should panic here
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        #[should_panic(expected = "Expected a non-empty collection, got {}")]
+        fn it_fails_on_empty_map() {
+            let _ = BTreeMap::<i32, i32>::new().assert_non_empty();
+            //                                  ^-- should panic here
+        }
+    }
+
+    mod debug_assert_non_empty {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_non_empty_str() {
+            let x = "42".debug_assert_non_empty();
+            assert_eq!(x, "42");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a non-empty collection, got []")
+        )]
+        fn it_fails_on_empty_slice() {
+            let x: &[i32] = &[];
+            let x = x.debug_assert_non_empty();
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert!(x.is_empty());
+        }
+    }
+
+    mod assert_len {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_same_length() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.assert_len(3).iter().sum::<i32>();
+            assert_eq!(x, 6);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a collection of length 2, got length 3: [1, 2, 3]")]
+        fn it_fails_on_different_length() {
+            let x: &[i32] = &[1, 2, 3];
+            let _ = x.assert_len(2);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a collection of length 0, got length 10: \
+                                   [0, 1, 2, 3, 4, 5, 6, 7, ..]")]
+        fn it_fails_with_truncated_contents() {
+            let x: &[i32] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            let _ = x.assert_len(0);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a collection of length 1, got length 200: \
+                                   \"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"..")]
+        fn it_fails_with_truncated_str() {
+            let x = core::str::from_utf8(&[b'a'; 200]).unwrap();
+            let _ = x.assert_len(1);
+            //        ^-- should panic here
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        #[should_panic(
+            expected = "Expected a collection of length 1, got length 2: {1: \"a\", 2: \"b\"}"
+        )]
+        fn it_fails_on_different_map_length() {
+            let map: BTreeMap<_, _> = [(1, "a"), (2, "b")].into_iter().collect();
+            let _ = map.assert_len(1);
+            //          ^-- should panic here
+        }
+    }
+
+    mod debug_assert_len {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_same_length() {
+            let x = "abc".debug_assert_len(3);
+            assert_eq!(x, "abc");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a collection of length 4, got length 3: \"abc\"")
+        )]
+        fn it_fails_on_different_length() {
+            let x = "abc".debug_assert_len(4);
+            //            ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "abc");
+        }
+    }
+
+    mod assert_contains {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_contained_item() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.assert_contains(&2).len();
+            assert_eq!(x, 3);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a collection containing 4, got length 3: [1, 2, 3]")]
+        fn it_fails_on_missing_item() {
+            let x: &[i32] = &[1, 2, 3];
+            let _ = x.assert_contains(&4);
+            //        ^-- should panic here
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        #[should_panic(expected = "Expected a collection containing \"c\", got length 1: [\"a\"]")]
+        fn it_fails_on_missing_item_in_set() {
+            let set: HashSet<_> = ["a"].into_iter().collect();
+            let _ = set.assert_contains(&"c");
+            //          ^-- should panic here
+        }
+    }
+
+    mod debug_assert_contains {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_contained_item() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_contains(&3);
+            assert_eq!(x, &[1, 2, 3]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a collection containing 0, got length 3: [1, 2, 3]")
+        )]
+        fn it_fails_on_missing_item() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_contains(&0);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &[1, 2, 3]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod assert_contains_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_contained_key() {
+            let map: BTreeMap<_, _> = [(1, "a"), (2, "b")].into_iter().collect();
+            let x = map.assert_contains_key(&1)[&1];
+            assert_eq!(x, "a");
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a map containing the key 3, got length 2: {1: \"a\", 2: \"b\"}"
+        )]
+        fn it_fails_on_missing_key() {
+            let map: BTreeMap<_, _> = [(1, "a"), (2, "b")].into_iter().collect();
+            let _ = (&map).assert_contains_key(&3);
+            //             ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod debug_assert_contains_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_contained_key() {
+            let map: HashMap<_, _> = [(1, "a")].into_iter().collect();
+            let x = map.debug_assert_contains_key(&1)[&1];
+            assert_eq!(x, "a");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a map containing the key 2, got length 1: {1: \"a\"}"
+            )
+        )]
+        fn it_fails_on_missing_key() {
+            let map: HashMap<_, _> = [(1, "a")].into_iter().collect();
+            let x = map.debug_assert_contains_key(&2);
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x.len(), 1);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod assert_not_contains_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_missing_key() {
+            let map: HashMap<_, _> = [(1, "a")].into_iter().collect();
+            let x = (&map).assert_not_contains_key(&2).len();
+            assert_eq!(x, 1);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a map not containing the key 1, got length 1: {1: \"a\"}"
+        )]
+        fn it_fails_on_contained_key() {
+            let map: HashMap<_, _> = [(1, "a")].into_iter().collect();
+            let _ = map.assert_not_contains_key(&1);
+            //          ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod debug_assert_not_contains_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_missing_key() {
+            let map: BTreeMap<_, _> = [(1, "a")].into_iter().collect();
+            let x = map.debug_assert_not_contains_key(&2);
+            assert_eq!(x.len(), 1);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a map not containing the key 1, got length 1: {1: \"a\"}"
+            )
+        )]
+        fn it_fails_on_contained_key() {
+            let map: BTreeMap<_, _> = [(1, "a")].into_iter().collect();
+            let x = map.debug_assert_not_contains_key(&1);
+            //          ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x.len(), 1);
+        }
+    }
+
+    mod assert_unique {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x: &[i32] = &[3, 1, 2];
+            let x = x.assert_unique().len();
+            assert_eq!(x, 3);
+        }
+
+        #[test]
+        fn it_succeeds_on_empty_slice() {
+            let x: &[i32] = &[];
+            let x = x.assert_unique();
+            assert!(x.is_empty());
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique items, got 1 at index 1 and 3")]
+        fn it_fails_on_first_duplicate_pair() {
+            let x: &[i32] = &[3, 1, 2, 1, 3];
+            let _ = x.assert_unique();
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique items, got 0.5 at index 0 and 2")]
+        fn it_compares_partial_eq_items() {
+            let x: &[f64] = &[0.5, 1.5, 0.5];
+            let _ = x.assert_unique();
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_unique {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x: &[&str] = &["a", "b"];
+            let x = x.debug_assert_unique();
+            assert_eq!(x, &["a", "b"]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected unique items, got \"a\" at index 0 and 1")
+        )]
+        fn it_fails_on_duplicate() {
+            let x: &[&str] = &["a", "a"];
+            let x = x.debug_assert_unique();
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &["a", "a"]);
+        }
+    }
+
+    mod assert_unique_by_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_keys() {
+            let x: &[(i32, &str)] = &[(1, "a"), (2, "a")];
+            let x = x.assert_unique_by_key(|x| x.0).len();
+            assert_eq!(x, 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique keys, got \"a\" at index 0 and 2")]
+        fn it_fails_on_duplicate_keys() {
+            let x: &[(i32, &str)] = &[(1, "a"), (2, "b"), (3, "a")];
+            let _ = x.assert_unique_by_key(|x| x.1);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_unique_by_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_keys() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_unique_by_key(|x| x % 4);
+            assert_eq!(x, &[1, 2, 3]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected unique keys, got 1 at index 0 and 2")
+        )]
+        fn it_fails_on_duplicate_keys() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_unique_by_key(|x| x % 2);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &[1, 2, 3]);
+        }
+    }
+
+    mod assert_permutation_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x: &[i32] = &[1, 1, 2, 3];
+            let x = x.assert_permutation_of(&[3, 1, 2, 1]);
+            assert_eq!(x, &[1, 1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a permutation of [3, 1, 2, 1], got [1, 2, 3]: missing [1], unexpected []"
+        )]
+        fn it_fails_on_missing_item() {
+            let x: &[i32] = &[1, 2, 3];
+            let _ = x.assert_permutation_of(&[3, 1, 2, 1]);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a permutation of [1, 2], got [1, 4, 4]: missing [2], unexpected [4, 4]"
+        )]
+        fn it_fails_on_different_items() {
+            let x: &[i32] = &[1, 4, 4];
+            let _ = x.assert_permutation_of(&[1, 2]);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_permutation_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_permutation_of(&[3, 2, 1]);
+            assert_eq!(x, &[1, 2, 3]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a permutation of [1, 2], got [2, 2]: missing [1], unexpected [2]"
+            )
+        )]
+        fn it_fails_on_different_items() {
+            let x: &[i32] = &[2, 2];
+            let x = x.debug_assert_permutation_of(&[1, 2]);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &[2, 2]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod assert_unique_hashed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x = vec!["c", "a", "b"].assert_unique_hashed();
+            assert_eq!(x, ["c", "a", "b"]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique items, got 1 at index 1 and 3")]
+        fn it_fails_on_first_duplicate_pair() {
+            let x: &[i32] = &[3, 1, 2, 1, 3];
+            let _ = x.assert_unique_hashed();
+            //        ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod debug_assert_unique_hashed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x: &[i32] = &[3, 1, 2];
+            let x = x.debug_assert_unique_hashed();
+            assert_eq!(x, &[3, 1, 2]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected unique items, got \"a\" at index 0 and 1")
+        )]
+        fn it_fails_on_duplicate() {
+            let x = vec!["a", "a"];
+            let x = (&x).debug_assert_unique_hashed();
+            //           ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &["a", "a"]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod assert_permutation_of_hashed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x = vec![1, 1, 2, 3].assert_permutation_of_hashed(&[3, 1, 2, 1]);
+            assert_eq!(x, [1, 1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a permutation of [1, 2, 2], got [1, 1, 2]: missing [2], unexpected [1]"
+        )]
+        fn it_fails_on_different_items() {
+            let x: &[i32] = &[1, 1, 2];
+            let _ = x.assert_permutation_of_hashed(&[1, 2, 2]);
+            //        ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod debug_assert_permutation_of_hashed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x: &[i32] = &[1, 2, 3];
+            let x = x.debug_assert_permutation_of_hashed(&[3, 2, 1]);
+            assert_eq!(x, &[1, 2, 3]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a permutation of [1, 2], got [2, 2]: missing [1], unexpected [2]"
+            )
+        )]
+        fn it_fails_on_different_items() {
+            let x = vec![2, 2];
+            let x = x.debug_assert_permutation_of_hashed(&[1, 2]);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [2, 2]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod collect_unique {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x = "a,b,c".split(',').collect_unique();
+            assert_eq!(x, ["a", "b", "c"]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique items, got 4 at index 0 and 2")]
+        fn it_fails_on_first_duplicate_pair() {
+            let _ = [-2, 1, 2].iter().map(|x| x * x).collect_unique();
+            //                                       ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod debug_collect_unique {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_items() {
+            let x = (1..4).map(|x| x * 2).debug_collect_unique();
+            assert_eq!(x, [2, 4, 6]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected unique items, got 0 at index 0 and 2")
+        )]
+        fn it_fails_on_duplicate() {
+            let x = (0..3).map(|x| x % 2).debug_collect_unique();
+            //                            ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [0, 1, 0]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod collect_unique_by_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_keys() {
+            let x = [(1, "a"), (2, "a")].iter().collect_unique_by_key(|x| x.0);
+            assert_eq!(x.len(), 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected unique keys, got \"a\" at index 0 and 1")]
+        fn it_fails_on_duplicate_key() {
+            let _ = [(1, "a"), (2, "a")].iter().collect_unique_by_key(|x| x.1);
+            //                                  ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod debug_collect_unique_by_key {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unique_keys() {
+            let x = [(1, "a"), (2, "a")];
+            let x = x.iter().debug_collect_unique_by_key(|x| x.0);
+            assert_eq!(x.len(), 2);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected unique keys, got \"a\" at index 0 and 1")
+        )]
+        fn it_fails_on_duplicate_key() {
+            let x = [(1, "a"), (2, "a")];
+            let x = x.iter().debug_collect_unique_by_key(|x| x.1);
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x.len(), 2);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod collect_permutation_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x = [3, 1, 2].iter().map(|x| x * 2);
+            let x = x.collect_permutation_of(&[2, 4, 6]);
+            assert_eq!(x, [6, 2, 4]);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a permutation of [1, 2, 3], got [1, 2]: missing [3], unexpected []"
+        )]
+        fn it_fails_on_missing_item() {
+            let _ = (1..3).collect_permutation_of(&[1, 2, 3]);
+            //             ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod debug_collect_permutation_of {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_permutation() {
+            let x = (1..4).rev().debug_collect_permutation_of(&[1, 2, 3]);
+            assert_eq!(x, [3, 2, 1]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a permutation of [1, 2], got [2, 2]: missing [1], unexpected [2]"
+            )
+        )]
+        fn it_fails_on_different_items() {
+            let x = [2, 2].into_iter().debug_collect_permutation_of(&[1, 2]);
+            //                         ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [2, 2]);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! A no-op `defmt` global logger for running the tests on the host.
+//!
+//! `defmt` requires the final binary to provide a global logger and a timestamp,
+//! which the test binaries and doctests built on the host do not have.
+//! This module is compiled only with `--cfg chain_assertions_defmt_logger`,
+//! so it never conflicts with the logger of a firmware using this crate.
+
+#[defmt::global_logger]
+struct Logger;
+
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(_bytes: &[u8]) {}
+}
+
+defmt::timestamp!("{=u32}", 0);
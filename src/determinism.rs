@@ -0,0 +1,335 @@
+//! Assertions for idempotent and deterministic computations.
+//!
+//! Use [`debug_assert_deterministic`] where the code relies on the same result for the same input,
+//! e.g. the simulation steps of lockstep multiplayer games:
+//!
+//! ```rust
+//! use chain_assertions::determinism::debug_assert_deterministic;
+//!
+//! fn step(positions: &[i32], velocity: i32) -> Vec<i32> {
+//!     positions.iter().map(|p| p + velocity).collect()
+//! }
+//!
+//! let positions = vec![0, 10];
+//! let positions = debug_assert_deterministic(|| step(&positions, 2));
+//! assert_eq!(positions, [2, 12]);
+//! ```
+
+/// An extension trait to add the assertion_idempotent methods.
+pub trait AssertIdempotentExt: Sized {
+    /// Asserts the function is idempotent on the value, i.e. `f(&f(&x)) == f(&x)`.
+    ///
+    /// The value itself is returned as is.
+    ///
+    /// # Panics
+    ///
+    /// If applying the function twice differs from applying it once, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "  Hello ".assert_idempotent(|s| s.trim()).trim();
+    /// assert_eq!(x, "Hello");
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 3.assert_idempotent(|x| x + 1);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_idempotent<F>(self, f: F) -> Self
+    where
+        F: Fn(&Self) -> Self;
+
+    /// Asserts the function is idempotent on the value only in debug builds.
+    ///
+    /// The function is called only when the value is checked.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Applying the function twice differs from applying it once
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// fn clamp(x: &i32) -> i32 {
+    ///     (*x).clamp(0, 100)
+    /// }
+    ///
+    /// let x = 120.debug_assert_idempotent(clamp);
+    /// assert_eq!(clamp(&x), 100);
+    /// ```
+    fn debug_assert_idempotent<F>(self, f: F) -> Self
+    where
+        F: Fn(&Self) -> Self;
+}
+
+#[track_caller]
+#[inline]
+fn check_idempotent<T, F>(value: &T, f: F)
+where
+    T: PartialEq + crate::Printable,
+    F: Fn(&T) -> T,
+{
+    let once = f(value);
+    let twice = f(&once);
+    if twice != once {
+        fail!(
+            NotIdempotent,
+            "Expected an idempotent function, got {:?} by applying it once and {:?} by twice to {:?}",
+            once,
+            twice,
+            value
+        );
+    }
+}
+
+impl<T> AssertIdempotentExt for T
+where
+    T: PartialEq + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_idempotent<F>(self, f: F) -> Self
+    where
+        F: Fn(&Self) -> Self,
+    {
+        check_idempotent(&self, f);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_idempotent<F>(self, _f: F) -> Self
+    where
+        F: Fn(&Self) -> Self,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_idempotent(&self, _f);
+        }
+        self
+    }
+}
+
+/// Runs the closure twice, asserts the results are equal and returns the first one.
+///
+/// The closure is `Fn` rather than `FnMut`, so that running it twice in debug builds and
+/// once in release builds does not change the state it would otherwise mutate.
+///
+/// # Panics
+///
+/// If the results differ, the function panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::determinism::assert_deterministic;
+///
+/// let x = assert_deterministic(|| [3, 1, 2].iter().max().copied());
+/// assert_eq!(x, Some(3));
+/// ```
+///
+/// ```rust,should_panic
+/// use chain_assertions::determinism::assert_deterministic;
+///
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// let _ = assert_deterministic(|| {
+///     std::thread::sleep(std::time::Duration::from_millis(1));
+///     start.elapsed()
+/// });
+/// // ^-- panics here
+/// ```
+#[track_caller]
+#[inline]
+pub fn assert_deterministic<R, F>(f: F) -> R
+where
+    R: PartialEq + crate::Printable,
+    F: Fn() -> R,
+{
+    let first = f();
+    check_deterministic(&first, f());
+    first
+}
+
+/// Runs the closure twice and asserts the results are equal only in debug builds,
+/// and returns the first result.
+///
+/// # Panics
+///
+/// The function panics if all following conditions are satisfied:
+///
+/// - The results differ
+/// - `debug_assertions` or `always-check` feature is enabled
+/// - `passthrough` feature is disabled
+///
+/// Otherwise, the closure runs only once and the function returns its result.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::determinism::debug_assert_deterministic;
+///
+/// let x = debug_assert_deterministic(|| "21".parse::<i32>()).map(|x| x * 2);
+/// assert_eq!(x, Ok(42));
+/// ```
+#[track_caller]
+#[inline]
+pub fn debug_assert_deterministic<R, F>(f: F) -> R
+where
+    R: PartialEq + crate::Printable,
+    F: Fn() -> R,
+{
+    let first = f();
+    #[cfg(all(
+        any(debug_assertions, feature = "always-check"),
+        not(feature = "passthrough")
+    ))]
+    {
+        check_deterministic(&first, f());
+    }
+    first
+}
+
+#[track_caller]
+#[inline]
+fn check_deterministic<R>(first: &R, second: R)
+where
+    R: PartialEq + crate::Printable,
+{
+    if second != *first {
+        fail!(
+            NotDeterministic,
+            "Expected a deterministic result, got {:?} and then {:?}",
+            first,
+            second
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_idempotent {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_idempotent_function() {
+            let x = (-5).assert_idempotent(|x: &i32| x.abs());
+            assert_eq!(x, -5);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected an idempotent function, \
+                                   got 4 by applying it once and 5 by twice to 3")]
+        fn it_fails_on_non_idempotent_function() {
+            let _ = 3.assert_idempotent(|x| x + 1);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_idempotent {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_idempotent_function() {
+            let x = 120.debug_assert_idempotent(|x| (*x).clamp(0, 100));
+            assert_eq!(x, 120);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected an idempotent function, \
+                                     got -3 by applying it once and 3 by twice to 3")
+        )]
+        fn it_fails_on_non_idempotent_function() {
+            let x = 3.debug_assert_idempotent(|x| -x);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 3);
+        }
+    }
+
+    mod assert_deterministic {
+        use super::super::*;
+        use core::cell::Cell;
+
+        #[test]
+        fn it_returns_first_result() {
+            let x = assert_deterministic(|| [1, 2, 3].iter().sum::<i32>());
+            assert_eq!(x, 6);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a deterministic result, got 1 and then 2")]
+        fn it_fails_on_different_results() {
+            let count = Cell::new(0);
+            let _ = assert_deterministic(|| {
+                count.set(count.get() + 1);
+                count.get()
+            });
+            // ^-- should panic here
+        }
+    }
+
+    mod debug_assert_deterministic {
+        use super::super::*;
+        use core::cell::Cell;
+
+        #[test]
+        fn it_runs_twice_only_in_debug_builds() {
+            let count = Cell::new(0);
+            let x = debug_assert_deterministic(|| {
+                count.set(count.get() + 1);
+                "same"
+            });
+            assert_eq!(x, "same");
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(count.get(), if checked { 2 } else { 1 });
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a deterministic result, got 10 and then 20")
+        )]
+        fn it_fails_on_different_results() {
+            let count = Cell::new(0);
+            let x = debug_assert_deterministic(|| {
+                count.set(count.get() + 1);
+                count.get() * 10
+            });
+            // ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 10);
+        }
+    }
+}
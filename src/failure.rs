@@ -0,0 +1,681 @@
+//! Failure reporting for the assertions.
+//!
+//! Every assertion reports its failure to the handler registered by [`set_failure_handler`]
+//! before it panics. The handler receives a [`Failure`] and can render it without heap
+//! allocation by using [`FailureMessage`], e.g. to log it or to store it into a static ring buffer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use chain_assertions::failure::{self, Failure, FailureMessage};
+//!
+//! fn handler(failure: &Failure<'_>) {
+//!     let message = FailureMessage::<256>::from_failure(failure);
+//!     // send `message.as_str()` to your logger here.
+//! }
+//!
+//! failure::set_failure_handler(handler);
+//! ```
+//!
+//! With the `std` feature, [`assertion_context`] appends the labels of the enclosing scopes
+//! to every failure message, e.g. `(in loading level 3 > parsing tiles)`,
+//! and [`AssertionLabelExt::with_assertion_label`] does the same for the rest of a chain.
+
+use crate::fmt;
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, cell::RefCell};
+
+/// The marker appended to a truncated [`FailureMessage`].
+const TRUNCATION_MARKER: &str = "...";
+
+static HANDLER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static CONTEXT: RefCell<Vec<Cow<'static, str>>> = RefCell::new(Vec::new());
+}
+
+/// The kind of an assertion failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureKind {
+    /// [`Ok`] was expected but [`Err`] was given.
+    ExpectedOk,
+    /// [`Err`] was expected but [`Ok`] was given.
+    ExpectedErr,
+    /// [`Some`] was expected but [`None`] was given.
+    ExpectedSome,
+    /// [`None`] was expected but [`Some`] was given.
+    ExpectedNone,
+    /// The user-provided condition returned `false`.
+    ConditionNotSatisfied,
+    /// The value was out of the expected range.
+    OutOfRange,
+    /// A positive number was expected.
+    NotPositive,
+    /// A non-zero number was expected.
+    Zero,
+    /// A finite floating-point number was expected.
+    NotFinite,
+    /// A floating-point number other than NaN was expected.
+    NaN,
+    /// The floating-point number was not approximately equal to the expected one.
+    NotApproxEqual,
+    /// A vector of the length `1` was expected.
+    NotNormalized,
+    /// The comparison with the bound was not satisfied.
+    ComparisonFailed,
+    /// A non-empty collection was expected.
+    Empty,
+    /// The collection did not have the expected length.
+    UnexpectedLength,
+    /// The collection did not contain the expected item.
+    NotContained,
+    /// The map did not contain the expected key.
+    MissingKey,
+    /// The map contained the unexpected key.
+    UnexpectedKey,
+    /// Unique items were expected but a duplicate was found.
+    Duplicate,
+    /// The items were not a permutation of the expected ones.
+    NotPermutation,
+    /// The string did not satisfy the expectation.
+    UnexpectedString,
+    /// An ASCII string was expected.
+    NotAscii,
+    /// Valid UTF-8 bytes were expected.
+    InvalidUtf8,
+    /// The bytes did not start with the expected prefix.
+    UnexpectedPrefix,
+    /// The address was not aligned as expected.
+    Misaligned,
+    /// Another variant of the enum was expected.
+    UnexpectedVariant,
+    /// An invariant of the value was violated.
+    InvariantViolated,
+    /// A precondition of the function was violated.
+    PreconditionViolated,
+    /// A postcondition of the function was violated.
+    PostconditionViolated,
+    /// The value did not match the matcher.
+    NotMatched,
+    /// The value differed from the one computed by the reference implementation.
+    CrossCheckFailed,
+    /// The value was not restored by decoding its encoding.
+    RoundtripFailed,
+    /// Applying the function twice differed from applying it once.
+    NotIdempotent,
+    /// Running the computation twice gave different results.
+    NotDeterministic,
+    /// The value was modified where it was expected to be unchanged.
+    Changed,
+}
+
+impl FailureKind {
+    /// Returns the name of the kind in snake case.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            FailureKind::ExpectedOk => "expected_ok",
+            FailureKind::ExpectedErr => "expected_err",
+            FailureKind::ExpectedSome => "expected_some",
+            FailureKind::ExpectedNone => "expected_none",
+            FailureKind::ConditionNotSatisfied => "condition_not_satisfied",
+            FailureKind::OutOfRange => "out_of_range",
+            FailureKind::NotPositive => "not_positive",
+            FailureKind::Zero => "zero",
+            FailureKind::NotFinite => "not_finite",
+            FailureKind::NaN => "nan",
+            FailureKind::NotApproxEqual => "not_approx_equal",
+            FailureKind::NotNormalized => "not_normalized",
+            FailureKind::ComparisonFailed => "comparison_failed",
+            FailureKind::Empty => "empty",
+            FailureKind::UnexpectedLength => "unexpected_length",
+            FailureKind::NotContained => "not_contained",
+            FailureKind::MissingKey => "missing_key",
+            FailureKind::UnexpectedKey => "unexpected_key",
+            FailureKind::Duplicate => "duplicate",
+            FailureKind::NotPermutation => "not_permutation",
+            FailureKind::UnexpectedString => "unexpected_string",
+            FailureKind::NotAscii => "not_ascii",
+            FailureKind::InvalidUtf8 => "invalid_utf8",
+            FailureKind::UnexpectedPrefix => "unexpected_prefix",
+            FailureKind::Misaligned => "misaligned",
+            FailureKind::UnexpectedVariant => "unexpected_variant",
+            FailureKind::InvariantViolated => "invariant_violated",
+            FailureKind::PreconditionViolated => "precondition_violated",
+            FailureKind::PostconditionViolated => "postcondition_violated",
+            FailureKind::NotMatched => "not_matched",
+            FailureKind::CrossCheckFailed => "cross_check_failed",
+            FailureKind::RoundtripFailed => "roundtrip_failed",
+            FailureKind::NotIdempotent => "not_idempotent",
+            FailureKind::NotDeterministic => "not_deterministic",
+            FailureKind::Changed => "changed",
+        }
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An assertion failure passed to the failure handler.
+#[derive(Clone, Copy, Debug)]
+pub struct Failure<'a> {
+    kind: FailureKind,
+    location: &'static Location<'static>,
+    message: fmt::Arguments<'a>,
+}
+
+impl<'a> Failure<'a> {
+    /// Returns the kind of the failure.
+    pub fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// Returns the location where the failed assertion was called.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the message of the failure, which is the same as the panic message.
+    pub fn message(&self) -> fmt::Arguments<'a> {
+        self.message
+    }
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(self.message)
+    }
+}
+
+/// A fixed-capacity buffer to render a failure message without heap allocation.
+///
+/// The text exceeding the capacity `N` is dropped and the message ends with `...`
+/// to indicate the truncation.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::failure::FailureMessage;
+/// use core::fmt::Write;
+///
+/// let mut message = FailureMessage::<8>::new();
+/// write!(message, "{:?}", [1, 2, 3, 4]).unwrap();
+/// assert_eq!(message.as_str(), "[1, 2...");
+/// assert!(message.is_truncated());
+/// ```
+#[derive(Clone)]
+pub struct FailureMessage<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> FailureMessage<N> {
+    /// Creates an empty message.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Renders the kind, the location and the message of the failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::failure::{Failure, FailureMessage};
+    ///
+    /// fn handler(failure: &Failure<'_>) {
+    ///     let message = FailureMessage::<256>::from_failure(failure);
+    ///     assert!(message.as_str().starts_with("expected_ok at "));
+    /// }
+    /// ```
+    pub fn from_failure(failure: &Failure<'_>) -> Self {
+        let mut message = Self::new();
+        let _ = fmt::Write::write_fmt(
+            &mut message,
+            format_args!(
+                "{} at {}: {}",
+                failure.kind(),
+                failure.location(),
+                failure.message()
+            ),
+        );
+        message
+    }
+
+    /// Returns the rendered message.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+
+    /// Returns `true` if the message was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Truncates the message and appends the truncation marker.
+    fn truncate(&mut self, rest: &str) {
+        self.truncated = true;
+
+        let limit = N.saturating_sub(TRUNCATION_MARKER.len());
+        if self.len < limit {
+            let mut n = limit - self.len;
+            while !rest.is_char_boundary(n) {
+                n -= 1;
+            }
+            self.push(&rest.as_bytes()[..n]);
+        } else {
+            self.len = limit;
+            while self.len > 0 && self.buf[self.len] & 0xC0 == 0x80 {
+                self.len -= 1;
+            }
+        }
+
+        let marker = TRUNCATION_MARKER.as_bytes();
+        let n = marker.len().min(N - self.len);
+        self.push(&marker[..n]);
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+}
+
+impl<const N: usize> Default for FailureMessage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for FailureMessage<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            // drops the rest silently
+        } else if s.len() <= N - self.len {
+            self.push(s.as_bytes());
+        } else {
+            self.truncate(s);
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Display for FailureMessage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FailureMessage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Registers the handler called on every assertion failure before panicking.
+///
+/// The handler replaces the previously registered one.
+pub fn set_failure_handler(handler: fn(&Failure<'_>)) {
+    HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+/// Unregisters the failure handler.
+pub fn reset_failure_handler() {
+    HANDLER.store(core::ptr::null_mut(), Ordering::Release);
+}
+
+fn failure_handler() -> Option<fn(&Failure<'_>)> {
+    let handler = HANDLER.load(Ordering::Acquire);
+    if handler.is_null() {
+        None
+    } else {
+        // SAFETY: the non-null pointer is always stored from `fn(&Failure<'_>)`.
+        Some(unsafe { core::mem::transmute::<*mut (), fn(&Failure<'_>)>(handler) })
+    }
+}
+
+/// Runs the closure with the label pushed to the assertion context of the current thread.
+///
+/// Every failure raised inside the closure ends with the labels of the enclosing contexts,
+/// from the outermost to the innermost, e.g. `(in loading level 3 > parsing tiles)`.
+/// The label is popped when the closure returns or panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::failure::assertion_context;
+/// use chain_assertions::prelude::*;
+///
+/// let level = 3;
+/// let tiles = assertion_context(format!("loading level {}", level), || {
+///     let tiles: Result<Vec<u8>, &str> = Ok(vec![1, 2, 3]);
+///     assertion_context("parsing tiles", || tiles.assert_ok())
+/// });
+/// assert_eq!(tiles, Ok(vec![1, 2, 3]));
+/// ```
+#[cfg(feature = "std")]
+pub fn assertion_context<L, F, R>(label: L, f: F) -> R
+where
+    L: Into<Cow<'static, str>>,
+    F: FnOnce() -> R,
+{
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CONTEXT.with(|context| context.borrow_mut().pop());
+        }
+    }
+
+    CONTEXT.with(|context| context.borrow_mut().push(label.into()));
+    let _guard = Guard;
+    f()
+}
+
+/// An extension trait to add the with_assertion_label method to [`Option`] and [`Result`].
+#[cfg(feature = "std")]
+pub trait AssertionLabelExt: Sized {
+    /// Runs the rest of the chain with the label pushed to the assertion context.
+    ///
+    /// Every failure raised by the assertions in the closure ends with the label,
+    /// e.g. `(in after decrypt)`, in the same way as [`assertion_context`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let key: Result<&str, &str> = Ok("secret");
+    /// let text = key.assert_ok().with_assertion_label("after decrypt", |key| {
+    ///     key.map(|key| key.to_uppercase())
+    ///         .assert_ok_and(|text| !text.is_empty())
+    /// });
+    /// assert_eq!(text, Ok("SECRET".to_string()));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let key: Result<&str, &str> = Ok("");
+    /// let _ = key.with_assertion_label("after decrypt", |key| {
+    ///     key.assert_ok_and(|key| !key.is_empty())
+    ///     //  ^-- panics with "... (in after decrypt)"
+    /// });
+    /// ```
+    fn with_assertion_label<L, F, R>(self, label: L, f: F) -> R
+    where
+        L: Into<Cow<'static, str>>,
+        F: FnOnce(Self) -> R;
+}
+
+#[cfg(feature = "std")]
+impl<T> AssertionLabelExt for Option<T> {
+    #[inline]
+    fn with_assertion_label<L, F, R>(self, label: L, f: F) -> R
+    where
+        L: Into<Cow<'static, str>>,
+        F: FnOnce(Self) -> R,
+    {
+        assertion_context(label, || f(self))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E> AssertionLabelExt for Result<T, E> {
+    #[inline]
+    fn with_assertion_label<L, F, R>(self, label: L, f: F) -> R
+    where
+        L: Into<Cow<'static, str>>,
+        F: FnOnce(Self) -> R,
+    {
+        assertion_context(label, || f(self))
+    }
+}
+
+/// The labels of the enclosing [`assertion_context`] scopes.
+#[cfg(feature = "std")]
+struct Context;
+
+#[cfg(feature = "std")]
+impl Context {
+    fn is_empty() -> bool {
+        CONTEXT.with(|context| {
+            context
+                .try_borrow()
+                .map_or(true, |labels| labels.is_empty())
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        CONTEXT.with(|context| {
+            let labels = match context.try_borrow() {
+                Ok(labels) => labels,
+                Err(_) => return Ok(()),
+            };
+            for (i, label) in labels.iter().enumerate() {
+                f.write_str(if i == 0 { " (in " } else { " > " })?;
+                f.write_str(label)?;
+            }
+            if !labels.is_empty() {
+                f.write_str(")")?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Calls the failure handler and panics with the message and the assertion context.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn fail(kind: FailureKind, message: fmt::Arguments<'_>) -> ! {
+    #[cfg(feature = "std")]
+    {
+        if !Context::is_empty() {
+            report(kind, format_args!("{}{}", message, Context));
+        }
+    }
+    report(kind, message)
+}
+
+#[track_caller]
+fn report(kind: FailureKind, message: fmt::Arguments<'_>) -> ! {
+    let failure = Failure {
+        kind,
+        location: Location::caller(),
+        message,
+    };
+    if let Some(handler) = failure_handler() {
+        handler(&failure);
+    }
+    panic!("{}", message)
+}
+
+#[cfg(test)]
+mod tests {
+    mod failure_message {
+        use super::super::*;
+        use crate::fmt::Write;
+
+        #[test]
+        fn it_renders_whole_text_within_capacity() {
+            let mut message = FailureMessage::<16>::new();
+            write!(message, "Some({:?})", 42).unwrap();
+
+            assert_eq!(message.as_str(), "Some(42)");
+            assert!(!message.is_truncated());
+        }
+
+        #[test]
+        fn it_renders_exactly_the_capacity() {
+            let mut message = FailureMessage::<8>::new();
+            message.write_str("12345678").unwrap();
+
+            assert_eq!(message.as_str(), "12345678");
+            assert!(!message.is_truncated());
+        }
+
+        #[test]
+        fn it_truncates_overflowed_text() {
+            let mut message = FailureMessage::<8>::new();
+            message.write_str("1234").unwrap();
+            message.write_str("56789").unwrap();
+
+            assert_eq!(message.as_str(), "12345...");
+            assert!(message.is_truncated());
+        }
+
+        #[test]
+        fn it_truncates_already_written_text_to_make_room_for_marker() {
+            let mut message = FailureMessage::<8>::new();
+            message.write_str("1234567").unwrap();
+            message.write_str("89").unwrap();
+
+            assert_eq!(message.as_str(), "12345...");
+            assert!(message.is_truncated());
+        }
+
+        #[test]
+        fn it_truncates_at_char_boundary() {
+            let mut message = FailureMessage::<8>::new();
+            message.write_str("あああ").unwrap();
+
+            assert_eq!(message.as_str(), "あ...");
+            assert!(message.is_truncated());
+        }
+
+        #[test]
+        fn it_renders_only_marker_into_tiny_buffer() {
+            let mut message = FailureMessage::<2>::new();
+            message.write_str("123").unwrap();
+
+            assert_eq!(message.as_str(), "..");
+            assert!(message.is_truncated());
+        }
+
+        #[test]
+        fn it_renders_failure() {
+            let failure = Failure {
+                kind: FailureKind::ExpectedNone,
+                location: Location::caller(),
+                message: format_args!("Expected None, got Some({:?})", "a long text"),
+            };
+            let message = FailureMessage::<256>::from_failure(&failure);
+
+            assert!(message
+                .as_str()
+                .starts_with("expected_none at src/failure.rs:"));
+            assert!(message
+                .as_str()
+                .ends_with(": Expected None, got Some(\"a long text\")"));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod failure_handler {
+        use super::super::*;
+        use crate::AssertNoneExt;
+        use core::sync::atomic::AtomicBool;
+
+        static CALLED: AtomicBool = AtomicBool::new(false);
+
+        fn handler(failure: &Failure<'_>) {
+            if failure.location().file() == file!() {
+                let message = FailureMessage::<256>::from_failure(failure);
+                let expected = "expected_none at src/failure.rs";
+                CALLED.store(message.as_str().starts_with(expected), Ordering::SeqCst);
+            }
+        }
+
+        #[test]
+        fn it_calls_handler_before_panicking() {
+            set_failure_handler(handler);
+            let result = std::panic::catch_unwind(|| Some(42).assert_none());
+            reset_failure_handler();
+
+            assert!(result.is_err(), "Expected panic");
+            assert!(CALLED.load(Ordering::SeqCst), "Expected handler called");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod assertion_context {
+        use super::super::*;
+        use crate::{AssertOkAndExt, AssertOkExt, AssertSomeExt};
+
+        #[test]
+        fn it_returns_result_of_closure() {
+            let x = assertion_context("loading level 3", || Some(42).assert_some());
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"oops\") (in loading level 3)")]
+        fn it_appends_label() {
+            let x: Result<i32, &str> = Err("oops");
+            let _ = assertion_context("loading level 3", || x.assert_ok());
+            //                                               ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected Some(_), got None (in loading level 3 > parsing tiles)"
+        )]
+        fn it_appends_nested_labels() {
+            let level = 3;
+            let _ = assertion_context(std::format!("loading level {}", level), || {
+                assertion_context("parsing tiles", || None::<i32>.assert_some())
+                //                                                ^-- should panic here
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = "Condition not satisfied for Ok(\"\") (in after decrypt)")]
+        fn it_appends_label_of_chain_step_to_later_failures() {
+            let key: Result<&str, &str> = Ok("");
+            let _ = key
+                .assert_ok()
+                .with_assertion_label("after decrypt", |key| {
+                    key.map(|key| key.trim())
+                        .assert_ok_and(|key| !key.is_empty())
+                    //   ^-- should panic here
+                });
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_), got None (in parsing tiles)")]
+        fn it_appends_label_of_option_step_to_later_failures() {
+            let tiles = Some("1,2,x");
+            let _ = tiles.with_assertion_label("parsing tiles", |tiles| {
+                tiles
+                    .and_then(|tiles| tiles.split(',').find(|tile| tile.is_empty()))
+                    .assert_some()
+                //   ^-- should panic here
+            });
+        }
+
+        #[test]
+        fn it_pops_label_on_exit() {
+            let result = std::panic::catch_unwind(|| {
+                assertion_context("loading level 3", || None::<i32>.assert_some())
+            });
+            assert!(result.is_err(), "Expected panic");
+
+            let _ = assertion_context("loading level 4", || 42);
+            assert!(Context::is_empty());
+        }
+    }
+}
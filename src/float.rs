@@ -0,0 +1,983 @@
+//! Assertions for floating-point numbers, and arrays or slices of them.
+//!
+//! The assertions on arrays and slices check every component and report the index of the first
+//! offending component.
+
+/// An extension trait to add the assertion_finite methods.
+pub trait AssertFiniteExt {
+    /// Asserts the number, or every component, is neither infinite nor NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the number or any component is infinite or NaN, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.0f32.assert_finite() * 2.0;
+    /// assert_eq!(x, 42.0);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [0.0, f64::INFINITY].assert_finite();
+    /// //                           ^-- panics here
+    /// ```
+    fn assert_finite(self) -> Self;
+
+    /// Asserts the number, or every component, is neither infinite nor NaN only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number or any component is infinite or NaN
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [1.0f32, 2.0, 3.0].debug_assert_finite();
+    /// assert_eq!(x, [1.0, 2.0, 3.0]);
+    /// ```
+    fn debug_assert_finite(self) -> Self;
+}
+
+/// An extension trait to add the assertion_not_nan methods.
+pub trait AssertNotNanExt {
+    /// Asserts the number, or every component, is not NaN.
+    ///
+    /// # Panics
+    ///
+    /// If the number or any component is NaN, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = f32::INFINITY.assert_not_nan();
+    /// assert_eq!(x, f32::INFINITY);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = (0.0f64 / 0.0).assert_not_nan();
+    /// //                     ^-- panics here
+    /// ```
+    fn assert_not_nan(self) -> Self;
+
+    /// Asserts the number, or every component, is not NaN only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number or any component is NaN
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [1.0f64, 2.0].debug_assert_not_nan();
+    /// assert_eq!(x, [1.0, 2.0]);
+    /// ```
+    fn debug_assert_not_nan(self) -> Self;
+}
+
+/// An extension trait to add the assertion_approx_eq methods.
+pub trait AssertApproxEqExt: Sized {
+    /// The type of the number or the components.
+    type Scalar;
+
+    /// Asserts the number, or every component, is equal to the expected one within the epsilon.
+    ///
+    /// # Panics
+    ///
+    /// If the absolute difference of the number or any component is greater than the epsilon,
+    /// or the lengths of the slices differ, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = (0.1f64 + 0.2).assert_approx_eq(0.3, 1e-9);
+    /// assert_ne!(x, 0.3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [0.1f32, 0.2].assert_approx_eq([0.1, 0.3], 1e-6);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self;
+
+    /// Asserts the number, or every component, is equal to the expected one within the epsilon
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The absolute difference of the number or any component is greater than the epsilon,
+    ///   or the lengths of the slices differ
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [0.5f32, 1.0].debug_assert_approx_eq([0.5, 1.0], 1e-6);
+    /// assert_eq!(x, [0.5, 1.0]);
+    /// ```
+    fn debug_assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self;
+
+    /// Asserts the number, or every component, is equal to the expected one within the number of
+    /// [ULPs](https://en.wikipedia.org/wiki/Unit_in_the_last_place).
+    ///
+    /// `0.0` and `-0.0` are treated as equal, and NaN is never equal to anything.
+    ///
+    /// # Panics
+    ///
+    /// If the number or any component is more than `max_ulps` ULPs away from the expected one,
+    /// or the lengths of the slices differ, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = (0.1f64 + 0.2).assert_approx_eq_ulps(0.3, 1);
+    /// assert_ne!(x, 0.3);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 1.0f32.assert_approx_eq_ulps(1.001, 4);
+    /// //             ^-- panics here
+    /// ```
+    fn assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self;
+
+    /// Asserts the number, or every component, is equal to the expected one within the number of
+    /// ULPs only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number or any component is more than `max_ulps` ULPs away from the expected one,
+    ///   or the lengths of the slices differ
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [1.0f64, 2.0].debug_assert_approx_eq_ulps([1.0, 2.0], 0);
+    /// assert_eq!(x, [1.0, 2.0]);
+    /// ```
+    fn debug_assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self;
+}
+
+/// An extension trait to add the assertion_normalized methods.
+pub trait AssertNormalizedExt: Sized {
+    /// The type of the components.
+    type Scalar;
+
+    /// Asserts the vector has the length of `1` within the epsilon.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the vector is not within `1 ± epsilon`, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [0.6f32, 0.8, 0.0].assert_normalized(1e-6);
+    /// assert_eq!(x, [0.6, 0.8, 0.0]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [1.0f32, 1.0].assert_normalized(1e-6);
+    /// //                    ^-- panics here
+    /// ```
+    fn assert_normalized(self, epsilon: Self::Scalar) -> Self;
+
+    /// Asserts the vector has the length of `1` within the epsilon only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The length of the vector is not within `1 ± epsilon`
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = [0.0f64, 1.0].debug_assert_normalized(1e-9);
+    /// assert_eq!(x, [0.0, 1.0]);
+    /// ```
+    fn debug_assert_normalized(self, epsilon: Self::Scalar) -> Self;
+}
+
+macro_rules! impl_float_assertions {
+    ($f:ident, $i:ident, $ulps:ident, $approx_eq:ident) => {
+        /// Returns the distance in ULPs, or `None` if any of them is NaN.
+        fn $ulps(a: $f, b: $f) -> Option<u128> {
+            // maps the sign-magnitude representation to the two's complement one,
+            // so that adjacent numbers have adjacent integers and `-0.0` equals to `0.0`
+            fn ordered(x: $f) -> i128 {
+                let bits = x.to_bits() as $i;
+                i128::from(if bits < 0 { $i::MIN.wrapping_sub(bits) } else { bits })
+            }
+
+            if a.is_nan() || b.is_nan() {
+                None
+            } else {
+                Some((ordered(a) - ordered(b)).unsigned_abs())
+            }
+        }
+
+        fn $approx_eq(a: $f, b: $f, epsilon: $f) -> bool {
+            let diff = if a > b { a - b } else { b - a };
+            a == b || diff <= epsilon
+        }
+
+        impl AssertFiniteExt for $f {
+            #[track_caller]
+            #[inline]
+            fn assert_finite(self) -> Self {
+                if !self.is_finite() {
+                    let value = self;
+                    fail!(NotFinite, "Expected a finite value, got {:?}", value);
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_finite(self) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    if !self.is_finite() {
+                        let value = self;
+                        fail!(NotFinite, "Expected a finite value, got {:?}", value);
+                    }
+                }
+                self
+            }
+        }
+
+        impl AssertFiniteExt for &[$f] {
+            #[track_caller]
+            #[inline]
+            fn assert_finite(self) -> Self {
+                if let Some(index) = self.iter().position(|x| !x.is_finite()) {
+                    let value = self[index];
+                    fail!(
+                        NotFinite,
+                        "Expected finite values, got {:?} at index {:?}",
+                        value,
+                        index
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_finite(self) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_finite();
+                }
+                self
+            }
+        }
+
+        impl<const N: usize> AssertFiniteExt for [$f; N] {
+            #[track_caller]
+            #[inline]
+            fn assert_finite(self) -> Self {
+                self[..].assert_finite();
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_finite(self) -> Self {
+                self[..].debug_assert_finite();
+                self
+            }
+        }
+
+        impl AssertNotNanExt for $f {
+            #[track_caller]
+            #[inline]
+            fn assert_not_nan(self) -> Self {
+                if self.is_nan() {
+                    let value = self;
+                    fail!(NaN, "Expected a value other than NaN, got {:?}", value);
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_not_nan(self) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_not_nan();
+                }
+                self
+            }
+        }
+
+        impl AssertNotNanExt for &[$f] {
+            #[track_caller]
+            #[inline]
+            fn assert_not_nan(self) -> Self {
+                if let Some(index) = self.iter().position(|x| x.is_nan()) {
+                    let value = self[index];
+                    fail!(
+                        NaN,
+                        "Expected values other than NaN, got {:?} at index {:?}",
+                        value,
+                        index
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_not_nan(self) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_not_nan();
+                }
+                self
+            }
+        }
+
+        impl<const N: usize> AssertNotNanExt for [$f; N] {
+            #[track_caller]
+            #[inline]
+            fn assert_not_nan(self) -> Self {
+                self[..].assert_not_nan();
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_not_nan(self) -> Self {
+                self[..].debug_assert_not_nan();
+                self
+            }
+        }
+
+        impl AssertApproxEqExt for $f {
+            type Scalar = $f;
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self {
+                if !$approx_eq(self, expected, epsilon) {
+                    let value = self;
+                    fail!(
+                        NotApproxEqual,
+                        "Expected {:?} ± {:?}, got {:?}",
+                        expected,
+                        epsilon,
+                        value
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq(self, _expected: Self, _epsilon: Self::Scalar) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_approx_eq(_expected, _epsilon);
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self {
+                match $ulps(self, expected) {
+                    Some(ulps) if ulps <= u128::from(max_ulps) => { /* do nothing */ }
+                    _ => {
+                        let value = self;
+                        fail!(
+                            NotApproxEqual,
+                            "Expected {:?} within {:?} ULPs, got {:?}",
+                            expected,
+                            max_ulps,
+                            value
+                        );
+                    }
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq_ulps(self, _expected: Self, _max_ulps: u32) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_approx_eq_ulps(_expected, _max_ulps);
+                }
+                self
+            }
+        }
+
+        impl AssertApproxEqExt for &[$f] {
+            type Scalar = $f;
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self {
+                if self.len() != expected.len() {
+                    let (expected, actual) = (expected.len(), self.len());
+                    fail!(
+                        NotApproxEqual,
+                        "Expected {:?} components, got {:?}",
+                        expected,
+                        actual
+                    );
+                }
+                let mismatch = self
+                    .iter()
+                    .zip(expected)
+                    .position(|(&a, &b)| !$approx_eq(a, b, epsilon));
+                if let Some(index) = mismatch {
+                    let (expected, value) = (expected[index], self[index]);
+                    fail!(
+                        NotApproxEqual,
+                        "Expected {:?} ± {:?} at index {:?}, got {:?}",
+                        expected,
+                        epsilon,
+                        index,
+                        value
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq(self, _expected: Self, _epsilon: Self::Scalar) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_approx_eq(_expected, _epsilon);
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self {
+                if self.len() != expected.len() {
+                    let (expected, actual) = (expected.len(), self.len());
+                    fail!(
+                        NotApproxEqual,
+                        "Expected {:?} components, got {:?}",
+                        expected,
+                        actual
+                    );
+                }
+                let mismatch = self.iter().zip(expected).position(|(&a, &b)| {
+                    !matches!($ulps(a, b), Some(ulps) if ulps <= u128::from(max_ulps))
+                });
+                if let Some(index) = mismatch {
+                    let (expected, value) = (expected[index], self[index]);
+                    fail!(
+                        NotApproxEqual,
+                        "Expected {:?} within {:?} ULPs at index {:?}, got {:?}",
+                        expected,
+                        max_ulps,
+                        index,
+                        value
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq_ulps(self, _expected: Self, _max_ulps: u32) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_approx_eq_ulps(_expected, _max_ulps);
+                }
+                self
+            }
+        }
+
+        impl<const N: usize> AssertApproxEqExt for [$f; N] {
+            type Scalar = $f;
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self {
+                self[..].assert_approx_eq(&expected[..], epsilon);
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq(self, expected: Self, epsilon: Self::Scalar) -> Self {
+                self[..].debug_assert_approx_eq(&expected[..], epsilon);
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self {
+                self[..].assert_approx_eq_ulps(&expected[..], max_ulps);
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_approx_eq_ulps(self, expected: Self, max_ulps: u32) -> Self {
+                self[..].debug_assert_approx_eq_ulps(&expected[..], max_ulps);
+                self
+            }
+        }
+
+        impl AssertNormalizedExt for &[$f] {
+            type Scalar = $f;
+
+            #[track_caller]
+            #[inline]
+            fn assert_normalized(self, epsilon: Self::Scalar) -> Self {
+                // compares the squared length to avoid `sqrt`, which is unavailable in `no_std`
+                let squared_length: $f = self.iter().map(|x| x * x).sum();
+                let (min, max) = (1.0 - epsilon, 1.0 + epsilon);
+                let min = if min > 0.0 { min * min } else { 0.0 };
+                if !(min..=max * max).contains(&squared_length) {
+                    let value = self;
+                    fail!(
+                        NotNormalized,
+                        "Expected a normalized vector within {:?}, got {:?} (squared length {:?})",
+                        epsilon,
+                        value,
+                        squared_length
+                    );
+                }
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_normalized(self, _epsilon: Self::Scalar) -> Self {
+                #[cfg(all(
+                    any(debug_assertions, feature = "always-check"),
+                    not(feature = "passthrough")
+                ))]
+                {
+                    self.assert_normalized(_epsilon);
+                }
+                self
+            }
+        }
+
+        impl<const N: usize> AssertNormalizedExt for [$f; N] {
+            type Scalar = $f;
+
+            #[track_caller]
+            #[inline]
+            fn assert_normalized(self, epsilon: Self::Scalar) -> Self {
+                self[..].assert_normalized(epsilon);
+                self
+            }
+
+            #[track_caller]
+            #[inline]
+            fn debug_assert_normalized(self, epsilon: Self::Scalar) -> Self {
+                self[..].debug_assert_normalized(epsilon);
+                self
+            }
+        }
+    };
+}
+
+impl_float_assertions!(f32, i32, ulps_f32, approx_eq_f32);
+impl_float_assertions!(f64, i64, ulps_f64, approx_eq_f64);
+
+#[cfg(test)]
+mod tests {
+    mod assert_finite {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_finite() {
+            let x = 21.0f32.assert_finite() * 2.0;
+            assert_eq!(x, 42.0);
+        }
+
+        #[test]
+        fn it_succeeds_on_finite_slice() {
+            let x: &[f64] = &[1.0, f64::MAX, f64::MIN_POSITIVE];
+            let x = x.assert_finite();
+            assert_eq!(x, &[1.0, f64::MAX, f64::MIN_POSITIVE]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a finite value, got -inf")]
+        fn it_fails_on_infinity() {
+            let _ = f64::NEG_INFINITY.assert_finite();
+            //                        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected finite values, got NaN at index 2")]
+        fn it_fails_on_array_with_nan() {
+            let _ = [1.0f32, 2.0, f32::NAN, f32::INFINITY].assert_finite();
+            //                                             ^-- should panic here
+        }
+    }
+
+    mod debug_assert_finite {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_finite() {
+            let x = [1.0f32, 2.0].debug_assert_finite();
+            assert_eq!(x, [1.0, 2.0]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected finite values, got inf at index 1")
+        )]
+        fn it_fails_on_infinity() {
+            let x = [1.0f64, f64::INFINITY].debug_assert_finite();
+            //                              ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [1.0, f64::INFINITY]);
+        }
+    }
+
+    mod assert_not_nan {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_infinity() {
+            let x = f64::INFINITY.assert_not_nan();
+            assert_eq!(x, f64::INFINITY);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value other than NaN, got NaN")]
+        fn it_fails_on_nan() {
+            let _ = f32::NAN.assert_not_nan();
+            //               ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected values other than NaN, got NaN at index 1")]
+        fn it_fails_on_slice_with_nan() {
+            let x: &[f64] = &[f64::INFINITY, f64::NAN];
+            let _ = x.assert_not_nan();
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_not_nan {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_number() {
+            let x = 1.0f32.debug_assert_not_nan();
+            assert_eq!(x, 1.0);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value other than NaN, got NaN")
+        )]
+        fn it_fails_on_nan() {
+            let x = f64::NAN.debug_assert_not_nan();
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert!(x.is_nan());
+        }
+    }
+
+    mod assert_approx_eq {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_within_epsilon() {
+            let x = (0.1f64 + 0.2).assert_approx_eq(0.3, 1e-9);
+            assert!(x > 0.3);
+        }
+
+        #[test]
+        fn it_succeeds_on_same_infinity() {
+            let x = f32::INFINITY.assert_approx_eq(f32::INFINITY, 0.0);
+            assert_eq!(x, f32::INFINITY);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 0.3 ± 0.01, got 0.5")]
+        fn it_fails_out_of_epsilon() {
+            let _ = 0.5f32.assert_approx_eq(0.3, 0.01);
+            //             ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 1.0 ± 0.0, got NaN")]
+        fn it_fails_on_nan() {
+            let _ = f64::NAN.assert_approx_eq(1.0, 0.0);
+            //               ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 0.3 ± 1e-6 at index 1, got 0.2")]
+        fn it_fails_on_array_component() {
+            let _ = [0.1f32, 0.2].assert_approx_eq([0.1, 0.3], 1e-6);
+            //                    ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 3 components, got 2")]
+        fn it_fails_on_slice_length_mismatch() {
+            let x: &[f64] = &[0.1, 0.2];
+            let _ = x.assert_approx_eq(&[0.1, 0.2, 0.3], 1e-6);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_approx_eq {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_within_epsilon() {
+            let x = [0.5f32, 1.0].debug_assert_approx_eq([0.5, 1.0], 1e-6);
+            assert_eq!(x, [0.5, 1.0]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected 2.0 ± 0.5, got 3.0")
+        )]
+        fn it_fails_out_of_epsilon() {
+            let x = 3.0f64.debug_assert_approx_eq(2.0, 0.5);
+            //             ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 3.0);
+        }
+    }
+
+    mod assert_approx_eq_ulps {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_within_ulps() {
+            let x = (0.1f64 + 0.2).assert_approx_eq_ulps(0.3, 1);
+            assert!(x > 0.3);
+        }
+
+        #[test]
+        fn it_succeeds_across_zero() {
+            let x = f32::from_bits(1).assert_approx_eq_ulps(-f32::from_bits(1), 2);
+            assert!(x > 0.0);
+        }
+
+        #[test]
+        fn it_succeeds_on_signed_zeros() {
+            let x = 0.0f64.assert_approx_eq_ulps(-0.0, 0);
+            assert_eq!(x, 0.0);
+        }
+
+        #[test]
+        fn it_succeeds_on_distant_values_with_max_ulps() {
+            let x = f32::MAX.assert_approx_eq_ulps(f32::MIN, u32::MAX);
+            assert_eq!(x, f32::MAX);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 1.001 within 4 ULPs, got 1.0")]
+        fn it_fails_out_of_ulps() {
+            let _ = 1.0f32.assert_approx_eq_ulps(1.001, 4);
+            //             ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected NaN within 4 ULPs, got NaN")]
+        fn it_fails_on_nan() {
+            let _ = f64::NAN.assert_approx_eq_ulps(f64::NAN, 4);
+            //               ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected 2.0 within 0 ULPs at index 1, got 2.0000002")]
+        fn it_fails_on_array_component() {
+            let x = [1.0f32, f32::from_bits(2.0f32.to_bits() + 1)];
+            let _ = x.assert_approx_eq_ulps([1.0, 2.0], 0);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_approx_eq_ulps {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_within_ulps() {
+            let x = [1.0f64, 2.0].debug_assert_approx_eq_ulps([1.0, 2.0], 0);
+            assert_eq!(x, [1.0, 2.0]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected 1.5 within 8 ULPs, got 1.0")
+        )]
+        fn it_fails_out_of_ulps() {
+            let x = 1.0f64.debug_assert_approx_eq_ulps(1.5, 8);
+            //             ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 1.0);
+        }
+    }
+
+    mod assert_normalized {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unit_vector() {
+            let x = [0.6f32, 0.8, 0.0].assert_normalized(1e-6);
+            assert_eq!(x, [0.6, 0.8, 0.0]);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a normalized vector within 0.001, got [1.0, 1.0] (squared length 2.0)"
+        )]
+        fn it_fails_on_long_vector() {
+            let _ = [1.0f64, 1.0].assert_normalized(1e-3);
+            //                    ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a normalized vector within 0.5, got []")]
+        fn it_fails_on_empty_slice() {
+            let x: &[f32] = &[];
+            let _ = x.assert_normalized(0.5);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_normalized {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_unit_vector() {
+            let x = [0.0f64, 1.0].debug_assert_normalized(1e-9);
+            assert_eq!(x, [0.0, 1.0]);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a normalized vector within 0.1, got [0.5] (squared length 0.25)"
+            )
+        )]
+        fn it_fails_on_short_vector() {
+            let x = [0.5f32].debug_assert_normalized(0.1);
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, [0.5]);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! Invariants of data structures and the assertions checking them.
+//!
+//! Implement [`Invariant`] by hand, or derive it with the `derive` feature:
+//!
+//! ```rust,ignore
+//! use chain_assertions::prelude::*;
+//! use chain_assertions::Invariant;
+//!
+//! #[derive(Invariant, Debug)]
+//! #[invariant(self.hp <= self.max_hp)]
+//! struct Player {
+//!     hp: u32,
+//!     max_hp: u32,
+//! }
+//!
+//! let player = Player { hp: 80, max_hp: 100 }.debug_assert_invariants();
+//! ```
+//!
+//! The derive also accepts `#[invariant]` on a field to check the invariants of the field.
+
+use crate::fmt;
+
+/// A data structure with invariants, i.e. the conditions that must always hold.
+pub trait Invariant {
+    /// Checks the invariants, and returns the first violated one as the error.
+    fn check_invariants(&self) -> Result<(), InvariantViolation>;
+}
+
+/// The violated invariant returned by [`Invariant::check_invariants`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvariantViolation {
+    condition: &'static str,
+}
+
+impl InvariantViolation {
+    /// Creates a violation of the invariant described by the condition, e.g. `self.hp <= self.max_hp`.
+    pub const fn new(condition: &'static str) -> Self {
+        Self { condition }
+    }
+
+    /// Returns the description of the violated invariant.
+    pub const fn condition(&self) -> &'static str {
+        self.condition
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invariant violated: {}", self.condition)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvariantViolation {}
+
+impl<T: ?Sized + Invariant> Invariant for &T {
+    #[inline]
+    fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        (**self).check_invariants()
+    }
+}
+
+impl<T: ?Sized + Invariant> Invariant for &mut T {
+    #[inline]
+    fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        (**self).check_invariants()
+    }
+}
+
+/// An extension trait to add the assertion_invariants methods.
+pub trait AssertInvariantsExt: Sized {
+    /// Asserts the invariants of the value hold.
+    ///
+    /// # Panics
+    ///
+    /// If any invariant is violated, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use chain_assertions::{Invariant, InvariantViolation};
+    ///
+    /// #[derive(Debug)]
+    /// struct Player {
+    ///     hp: u32,
+    ///     max_hp: u32,
+    /// }
+    ///
+    /// impl Invariant for Player {
+    ///     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    ///         if self.hp > self.max_hp {
+    ///             return Err(InvariantViolation::new("hp <= max_hp"));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let x = Player { hp: 80, max_hp: 100 }.assert_invariants().hp;
+    /// assert_eq!(x, 80);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    /// # use chain_assertions::{Invariant, InvariantViolation};
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Player {
+    /// #     hp: u32,
+    /// #     max_hp: u32,
+    /// # }
+    /// #
+    /// # impl Invariant for Player {
+    /// #     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    /// #         if self.hp > self.max_hp {
+    /// #             return Err(InvariantViolation::new("hp <= max_hp"));
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let _ = Player { hp: 120, max_hp: 100 }.assert_invariants();
+    /// //                                      ^-- panics here
+    /// ```
+    fn assert_invariants(self) -> Self;
+
+    /// Asserts the invariants of the value hold only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - Any invariant is violated
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use chain_assertions::{Invariant, InvariantViolation};
+    ///
+    /// #[derive(Debug)]
+    /// struct Range(u32, u32);
+    ///
+    /// impl Invariant for Range {
+    ///     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    ///         if self.0 > self.1 {
+    ///             return Err(InvariantViolation::new("start <= end"));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let x = Range(1, 5).debug_assert_invariants();
+    /// assert_eq!(x.1 - x.0, 4);
+    /// ```
+    fn debug_assert_invariants(self) -> Self;
+}
+
+#[track_caller]
+#[inline]
+pub(crate) fn check_invariants<T>(value: &T)
+where
+    T: ?Sized + Invariant + crate::Printable,
+{
+    if let Err(violation) = value.check_invariants() {
+        let condition = violation.condition();
+        fail!(
+            InvariantViolated,
+            "Invariant {:?} violated by {:?}",
+            condition,
+            value
+        );
+    }
+}
+
+impl<T> AssertInvariantsExt for T
+where
+    T: Invariant + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_invariants(self) -> Self {
+        check_invariants(&self);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_invariants(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_invariants(&self);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Player {
+        pub(crate) hp: u32,
+        pub(crate) max_hp: u32,
+    }
+
+    impl Invariant for Player {
+        fn check_invariants(&self) -> Result<(), InvariantViolation> {
+            if self.hp > self.max_hp {
+                return Err(InvariantViolation::new("self.hp <= self.max_hp"));
+            }
+            Ok(())
+        }
+    }
+
+    mod invariant_violation {
+        use super::super::*;
+
+        #[test]
+        fn it_displays_condition() {
+            let violation = InvariantViolation::new("self.hp <= self.max_hp");
+            assert_eq!(violation.condition(), "self.hp <= self.max_hp");
+
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                alloc::format!("{}", violation),
+                "invariant violated: self.hp <= self.max_hp"
+            );
+        }
+    }
+
+    mod assert_invariants {
+        use super::Player;
+        use crate::invariant::*;
+
+        #[test]
+        fn it_succeeds_on_valid_value() {
+            let x = Player {
+                hp: 80,
+                max_hp: 100,
+            }
+            .assert_invariants()
+            .hp;
+            assert_eq!(x, 80);
+        }
+
+        #[test]
+        fn it_succeeds_on_reference() {
+            let player = Player {
+                hp: 80,
+                max_hp: 100,
+            };
+            let x = (&player).assert_invariants();
+            assert_eq!(x.hp, 80);
+        }
+
+        #[test]
+        #[should_panic(expected = "Invariant \"self.hp <= self.max_hp\" violated by \
+                                   Player { hp: 120, max_hp: 100 }")]
+        fn it_fails_on_violation() {
+            let _ = Player {
+                hp: 120,
+                max_hp: 100,
+            }
+            .assert_invariants();
+            //                                      ^-- should panic here
+        }
+    }
+
+    mod debug_assert_invariants {
+        use super::Player;
+        use crate::invariant::*;
+
+        #[test]
+        fn it_succeeds_on_valid_value() {
+            let x = Player {
+                hp: 100,
+                max_hp: 100,
+            }
+            .debug_assert_invariants();
+            assert_eq!(
+                x,
+                Player {
+                    hp: 100,
+                    max_hp: 100
+                }
+            );
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Invariant \"self.hp <= self.max_hp\" violated by \
+                                     Player { hp: 101, max_hp: 100 }")
+        )]
+        fn it_fails_on_violation() {
+            let mut player = Player {
+                hp: 100,
+                max_hp: 100,
+            };
+            let x = (&mut player).debug_assert_invariants();
+            x.hp += 1;
+            let x = (&mut player).debug_assert_invariants();
+            //                    ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x.hp, 101);
+        }
+    }
+}
@@ -7,6 +7,72 @@ pub(crate) use core::fmt;
 #[cfg(feature = "std")]
 pub(crate) use std::fmt;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
+pub mod bytes;
+pub mod checkpoint;
+pub mod cmp;
+pub mod collection;
+#[cfg(all(feature = "defmt", chain_assertions_defmt_logger))]
+mod defmt_logger;
+pub mod determinism;
+pub mod failure;
+pub mod float;
+pub mod invariant;
+pub mod matcher;
+pub mod numeric;
 pub mod option;
 pub mod prelude;
+mod preview;
+mod printable;
 pub mod result;
+pub mod roundtrip;
+pub mod string;
+pub mod trace;
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
+#[cfg(feature = "derive")]
+pub use chain_assertions_derive::{contract, ChainAssertVariants, Invariant};
+
+pub use crate::bytes::{AssertAlignedToExt, AssertBytesExt};
+pub use crate::checkpoint::{AssertUnchangedByExt, DebugCheckpointExt};
+pub use crate::cmp::{AssertCmpExt, AssertCrossCheckExt};
+#[cfg(feature = "alloc")]
+pub use crate::collection::{AssertCollectExt, AssertContainsKeyExt, AssertNotContainsKeyExt};
+pub use crate::collection::{
+    AssertContainsExt, AssertLenExt, AssertNonEmptyExt, AssertPermutationOfExt,
+    AssertUniqueByKeyExt, AssertUniqueExt,
+};
+#[cfg(feature = "std")]
+pub use crate::collection::{AssertPermutationOfHashedExt, AssertUniqueHashedExt};
+pub use crate::determinism::AssertIdempotentExt;
+#[cfg(feature = "std")]
+pub use crate::failure::AssertionLabelExt;
+pub use crate::float::{AssertApproxEqExt, AssertFiniteExt, AssertNormalizedExt, AssertNotNanExt};
+pub use crate::invariant::{AssertInvariantsExt, Invariant, InvariantViolation};
+pub use crate::matcher::AssertMatchesExt;
+pub use crate::numeric::{AssertInRangeExt, AssertNonZeroExt, AssertPositiveExt};
+pub use crate::option::{
+    AssertNoneExt, AssertSomeAndExt, AssertSomeCmpExt, AssertSomeExt, AssertSomeInExt,
+    AssertSomeLabeledExt, AssertSomeMatchingExt, AssumeNoneExt, AssumeSomeExt, DebugInspectSomeExt,
+};
+pub use crate::printable::Printable;
+pub use crate::result::{
+    AssertErrAndExt, AssertErrExt, AssertOkAndExt, AssertOkCmpExt, AssertOkEqWithExt, AssertOkExt,
+    AssertOkInExt, AssertOkInvariantsExt, AssertOkLabeledExt, AssertOkMatchingExt, AssumeErrExt,
+    AssumeOkExt, DebugInspectErrExt, DebugInspectOkExt,
+};
+pub use crate::roundtrip::AssertRoundtripExt;
+#[cfg(feature = "serde_json")]
+pub use crate::roundtrip::AssertSerdeRoundtripExt;
+#[cfg(feature = "regex")]
+pub use crate::string::AssertMatchesRegexExt;
+pub use crate::string::AssertStrExt;
+pub use crate::trace::DebugTraceExt;
@@ -0,0 +1,522 @@
+/// Reports an assertion failure to the failure handler and panics.
+///
+/// The failure path is moved into a cold, non-generic function defined per call site,
+/// which receives the values as `&dyn Debug`, so it is not duplicated for each instantiation
+/// of the generic assertions.
+/// With the `defmt` feature, the failure is also logged through `defmt::error!`,
+/// printing the values with `defmt::Debug2Format`.
+///
+/// The format string must be accepted by both [`core::format_args`] and `defmt`,
+/// so only `{:?}` placeholders are allowed. The values must be passed as identifiers.
+macro_rules! fail {
+    ($kind:ident, $fmt:literal $(, $arg:ident)* $(,)?) => {{
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn fail($($arg: &dyn crate::fmt::Debug),*) -> ! {
+            #[cfg(feature = "defmt")]
+            defmt::error!($fmt $(, defmt::Debug2Format($arg))*);
+            crate::failure::fail(
+                crate::failure::FailureKind::$kind,
+                format_args!($fmt $(, $arg)*),
+            )
+        }
+        fail($(&$arg),*)
+    }};
+}
+
+/// Asserts the [`Result`] is [`Ok`] only if `debug_assertions` is enabled in the caller's crate.
+///
+/// Unlike [`AssertOkExt::debug_assert_ok`](crate::AssertOkExt::debug_assert_ok), the check is
+/// decided when compiling the crate calling the macro, and the `passthrough` and `always-check`
+/// features are ignored.
+/// So each crate can choose whether to check by `debug-assertions` in its Cargo profile.
+///
+/// # Panics
+///
+/// The macro panics if it is [`Err`] and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_ok;
+///
+/// let x: Result<i32, &str> = Ok(21);
+/// let x = chain_debug_assert_ok!(x).map(|x| x * 2);
+/// assert_eq!(x, Ok(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_ok {
+    ($value:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertOkExt::assert_ok(value)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Result`] is [`Ok`] and satisfies the condition only if `debug_assertions`
+/// is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`Err`], or [`Ok`] but the condition returns `false`,
+/// and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_ok_and;
+///
+/// let x: Result<i32, &str> = Ok(21);
+/// let x = chain_debug_assert_ok_and!(x, |x| x == &21).map(|x| x * 2);
+/// assert_eq!(x, Ok(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_ok_and {
+    ($value:expr, $cond:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertOkAndExt::assert_ok_and(value, $cond)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Result`] is [`Err`] only if `debug_assertions` is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`Ok`] and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_err;
+///
+/// let x: Result<&str, i32> = Err(21);
+/// let x = chain_debug_assert_err!(x).map_err(|x| x * 2);
+/// assert_eq!(x, Err(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_err {
+    ($value:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertErrExt::assert_err(value)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Result`] is [`Err`] and satisfies the condition only if `debug_assertions`
+/// is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`Ok`], or [`Err`] but the condition returns `false`,
+/// and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_err_and;
+///
+/// let x: Result<&str, i32> = Err(21);
+/// let x = chain_debug_assert_err_and!(x, |x| x == &21).map_err(|x| x * 2);
+/// assert_eq!(x, Err(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_err_and {
+    ($value:expr, $cond:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertErrAndExt::assert_err_and(value, $cond)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Option`] is [`Some`] only if `debug_assertions` is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`None`] and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_some;
+///
+/// let x: Option<i32> = Some(21);
+/// let x = chain_debug_assert_some!(x).map(|x| x * 2);
+/// assert_eq!(x, Some(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_some {
+    ($value:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertSomeExt::assert_some(value)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Option`] is [`Some`] and satisfies the condition only if `debug_assertions`
+/// is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`None`] or the condition returns `false`,
+/// and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_some_and;
+///
+/// let x: Option<i32> = Some(21);
+/// let x = chain_debug_assert_some_and!(x, |x| x >= &20).map(|x| x * 2);
+/// assert_eq!(x, Some(42));
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_some_and {
+    ($value:expr, $cond:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertSomeAndExt::assert_some_and(value, $cond)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the [`Option`] is [`None`] only if `debug_assertions` is enabled in the caller's crate.
+///
+/// See [`chain_debug_assert_ok!`] for how the check is decided.
+///
+/// # Panics
+///
+/// The macro panics if it is [`Some`] and `debug_assertions` is enabled in the caller's crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_debug_assert_none;
+///
+/// let x: Option<i32> = None;
+/// let x = chain_debug_assert_none!(x).map(|x| x * 2);
+/// assert_eq!(x, None);
+/// ```
+#[macro_export]
+macro_rules! chain_debug_assert_none {
+    ($value:expr $(,)?) => {{
+        let value = $value;
+        if cfg!(debug_assertions) {
+            $crate::AssertNoneExt::assert_none(value)
+        } else {
+            value
+        }
+    }};
+}
+
+/// Asserts the value satisfies the condition, and prints the source of the condition on failure.
+///
+/// The second argument is the kind of the assertion followed by a closure:
+///
+/// | Kind             | Equivalent method                                                       |
+/// |------------------|-------------------------------------------------------------------------|
+/// | `some_and`       | [`AssertSomeAndExt::assert_some_and`](crate::AssertSomeAndExt)          |
+/// | `ok_and`         | [`AssertOkAndExt::assert_ok_and`](crate::AssertOkAndExt)                |
+/// | `err_and`        | [`AssertErrAndExt::assert_err_and`](crate::AssertErrAndExt)             |
+/// | `debug_some_and` | [`AssertSomeAndExt::debug_assert_some_and`](crate::AssertSomeAndExt)    |
+/// | `debug_ok_and`   | [`AssertOkAndExt::debug_assert_ok_and`](crate::AssertOkAndExt)          |
+/// | `debug_err_and`  | [`AssertErrAndExt::debug_assert_err_and`](crate::AssertErrAndExt)       |
+///
+/// The `debug_*` kinds are checked according to the features of this crate like the methods.
+///
+/// # Panics
+///
+/// The macro panics like the equivalent method, but the message includes the body of the closure,
+/// e.g. ``Condition `x >= &20` not satisfied for Some(19)``.
+///
+/// # Examples
+///
+/// ```rust
+/// use chain_assertions::chain_assert;
+///
+/// let x: Option<i32> = Some(21);
+/// let x = chain_assert!(x, some_and |x| x >= &20).map(|x| x * 2);
+/// assert_eq!(x, Some(42));
+/// ```
+///
+/// ```rust,should_panic
+/// use chain_assertions::chain_assert;
+///
+/// let x: Result<&str, i32> = Ok("too long");
+/// let _ = chain_assert!(x, ok_and |s: &&str| s.len() < 5);
+/// //      ^-- panics here with "Condition `s.len() < 5` not satisfied for Ok(\"too long\")"
+/// ```
+#[macro_export]
+macro_rules! chain_assert {
+    (@call $f:path, $value:expr, |$x:ident: $ty:ty| $body:expr $(,)?) => {
+        $f($value, |$x: $ty| $body, stringify!($body))
+    };
+    (@call $f:path, $value:expr, |$x:pat_param| $body:expr $(,)?) => {
+        $f($value, |$x| $body, stringify!($body))
+    };
+    ($value:expr, some_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::some_and, $value, $($closure)+)
+    };
+    ($value:expr, ok_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::ok_and, $value, $($closure)+)
+    };
+    ($value:expr, err_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::err_and, $value, $($closure)+)
+    };
+    ($value:expr, debug_some_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::debug_some_and, $value, $($closure)+)
+    };
+    ($value:expr, debug_ok_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::debug_ok_and, $value, $($closure)+)
+    };
+    ($value:expr, debug_err_and $($closure:tt)+) => {
+        $crate::chain_assert!(@call $crate::__private::debug_err_and, $value, $($closure)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Debuggable;
+
+    mod chain_debug_assert_ok {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_ok() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = chain_debug_assert_ok!(x).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Expected Ok(_), got Err(Debuggable)")]
+        fn it_fails_on_err_regardless_of_passthrough() {
+            let x: Result<i32, Debuggable> = Err(Debuggable);
+            let x = chain_debug_assert_ok!(x).map(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Err(Debuggable));
+        }
+    }
+
+    mod chain_debug_assert_ok_and {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_satisfied_ok() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = chain_debug_assert_ok_and!(x, |x| x == &21).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Condition not satisfied for Ok(21)")]
+        fn it_fails_on_invalid_ok() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = chain_debug_assert_ok_and!(x, |x| x < &21).map(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Ok(42));
+        }
+    }
+
+    mod chain_debug_assert_err {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_err() {
+            let x: Result<Debuggable, i32> = Err(21);
+            let x = chain_debug_assert_err!(x).map_err(|x| x * 2);
+            assert_eq!(x, Err(42));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Expected Err(_), got Ok(Debuggable)")]
+        fn it_fails_on_ok() {
+            let x: Result<Debuggable, i32> = Ok(Debuggable);
+            let x = chain_debug_assert_err!(x).map_err(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Ok(Debuggable));
+        }
+    }
+
+    mod chain_debug_assert_err_and {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_satisfied_err() {
+            let x: Result<Debuggable, i32> = Err(21);
+            let x = chain_debug_assert_err_and!(x, |x| x == &21).map_err(|x| x * 2);
+            assert_eq!(x, Err(42));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Condition not satisfied for Err(21)")]
+        fn it_fails_on_invalid_err() {
+            let x: Result<Debuggable, i32> = Err(21);
+            let x = chain_debug_assert_err_and!(x, |x| x < &21).map_err(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Err(42));
+        }
+    }
+
+    mod chain_debug_assert_some {
+        #[test]
+        fn it_succeeds_on_some() {
+            let x: Option<i32> = Some(21);
+            let x = chain_debug_assert_some!(x).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Expected Some(_), got None")]
+        fn it_fails_on_none() {
+            let x: Option<i32> = None;
+            let x = chain_debug_assert_some!(x).map(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, None);
+        }
+    }
+
+    mod chain_debug_assert_some_and {
+        #[test]
+        fn it_succeeds_on_satisfied_some() {
+            let x: Option<i32> = Some(21);
+            let x = chain_debug_assert_some_and!(x, |x| x >= &20).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[cfg_attr(
+            debug_assertions,
+            should_panic = "Condition not satisfied for Some(19)"
+        )]
+        fn it_fails_on_invalid_some() {
+            let x: Option<i32> = Some(19);
+            let x = chain_debug_assert_some_and!(x, |x| x >= &20).map(|x| x * 2);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Some(38));
+        }
+    }
+
+    mod chain_debug_assert_none {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_none() {
+            let x: Option<Debuggable> = None;
+            let x = chain_debug_assert_none!(x);
+            assert_eq!(x, None);
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic = "Expected None, got Some(Debuggable)")]
+        fn it_fails_on_some() {
+            let x: Option<Debuggable> = Some(Debuggable);
+            let x = chain_debug_assert_none!(x);
+            //      ^-- panics here only if debug_assertions is enabled in this crate
+
+            // for release builds
+            assert_eq!(x, Some(Debuggable));
+        }
+    }
+
+    mod chain_assert {
+        use super::*;
+
+        #[test]
+        fn it_succeeds_on_satisfied_values() {
+            let x: Option<i32> = Some(21);
+            let x = chain_assert!(x, some_and | x | x >= &20).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = chain_assert!(x, ok_and | &x | x == 21);
+            assert_eq!(x, Ok(21));
+
+            let x: Result<Debuggable, i32> = Err(21);
+            let x = chain_assert!(x, err_and |x: &i32| *x == 21);
+            assert_eq!(x, Err(21));
+        }
+
+        #[test]
+        #[should_panic(expected = "Condition `x >= &20` not satisfied for Some(19)")]
+        fn it_prints_condition_for_some() {
+            let x: Option<i32> = Some(19);
+            let _ = chain_assert!(x, some_and | x | x >= &20);
+            //      ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Condition `s.len() < 5` not satisfied for Ok(\"too long\")")]
+        fn it_prints_condition_for_ok() {
+            let x: Result<&str, Debuggable> = Ok("too long");
+            let _ = chain_assert!(x, ok_and |s: &&str| s.len() < 5);
+            //      ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Err(_), got Ok(Debuggable)")]
+        fn it_fails_on_ok_for_err_and() {
+            let x: Result<Debuggable, i32> = Ok(Debuggable);
+            let _ = chain_assert!(x, err_and | x | *x == 21);
+            //      ^-- should panic here
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Condition `*e > 0` not satisfied for Err(0)")
+        )]
+        fn it_prints_condition_only_in_debug_builds() {
+            let x: Result<Debuggable, i32> = Err(0);
+            let x = chain_assert!(x, debug_err_and | e | *e > 0);
+            //      ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Err(0));
+        }
+    }
+}
@@ -0,0 +1,550 @@
+//! Composable matchers describing themselves on failure.
+//!
+//! A [`Matcher`] checks a value and describes what it expects,
+//! so a failure renders the nested expectation instead of an opaque condition:
+//!
+//! ```rust,should_panic
+//! use chain_assertions::matcher::{all_of, gt, lt, ok};
+//! use chain_assertions::prelude::*;
+//!
+//! let x: Result<i32, &str> = Ok(12);
+//! let _ = x.assert_that_matches(ok(all_of((gt(0), lt(10)))));
+//! //        ^-- panics with "Expected Ok(all of (greater than 0, less than 10)), got Ok(12)"
+//! ```
+//!
+//! The descriptions are rendered by [`core::fmt`].
+
+use crate::fmt;
+use core::marker::PhantomData;
+
+/// A condition on values of `T` which can describe itself.
+pub trait Matcher<T: ?Sized> {
+    /// Returns `true` if the value matches.
+    fn matches(&self, actual: &T) -> bool;
+
+    /// Writes the description of the matching values, e.g. `greater than 0`.
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<T, M> Matcher<T> for &M
+where
+    T: ?Sized,
+    M: ?Sized + Matcher<T>,
+{
+    #[inline]
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).describe(f)
+    }
+}
+
+/// A list of matchers combined by [`all_of`] or [`any_of`].
+///
+/// It is implemented for tuples of up to 8 matchers.
+pub trait MatcherList<T: ?Sized> {
+    /// Returns `true` if all matchers match.
+    fn matches_all(&self, actual: &T) -> bool;
+
+    /// Returns `true` if any matcher matches.
+    fn matches_any(&self, actual: &T) -> bool;
+
+    /// Writes the descriptions of the matchers separated by commas.
+    fn describe_each(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+macro_rules! impl_matcher_list {
+    ($(($m0:ident $i0:tt $(, $m:ident $i:tt)*))+) => {
+        $(
+            impl<T: ?Sized, $m0: Matcher<T> $(, $m: Matcher<T>)*> MatcherList<T> for ($m0, $($m,)*) {
+                #[inline]
+                fn matches_all(&self, actual: &T) -> bool {
+                    self.$i0.matches(actual) $(&& self.$i.matches(actual))*
+                }
+
+                #[inline]
+                fn matches_any(&self, actual: &T) -> bool {
+                    self.$i0.matches(actual) $(|| self.$i.matches(actual))*
+                }
+
+                fn describe_each(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.$i0.describe(f)?;
+                    $(
+                        f.write_str(", ")?;
+                        self.$i.describe(f)?;
+                    )*
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+impl_matcher_list! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+}
+
+/// The matcher returned by [`anything`].
+#[derive(Clone, Copy, Debug)]
+pub struct Anything;
+
+/// Matches any value.
+pub fn anything() -> Anything {
+    Anything
+}
+
+impl<T: ?Sized> Matcher<T> for Anything {
+    #[inline]
+    fn matches(&self, _actual: &T) -> bool {
+        true
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("anything")
+    }
+}
+
+macro_rules! impl_cmp_matchers {
+    ($($(#[$attr:meta])* $f:ident => $name:ident, $trait:ident, $op:tt, $description:literal;)+) => {
+        $(
+            #[doc = concat!("The matcher returned by [`", stringify!($f), "`].")]
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name<E>(E);
+
+            $(#[$attr])*
+            pub fn $f<E>(expected: E) -> $name<E> {
+                $name(expected)
+            }
+
+            impl<T, E> Matcher<T> for $name<E>
+            where
+                T: ?Sized + $trait<E>,
+                E: fmt::Debug,
+            {
+                #[inline]
+                fn matches(&self, actual: &T) -> bool {
+                    *actual $op self.0
+                }
+
+                fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, concat!($description, " {:?}"), self.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_cmp_matchers! {
+    /// Matches values equal to the expected value.
+    eq => EqualTo, PartialEq, ==, "equal to";
+    /// Matches values not equal to the expected value.
+    ne => NotEqualTo, PartialEq, !=, "not equal to";
+    /// Matches values less than the bound.
+    lt => LessThan, PartialOrd, <, "less than";
+    /// Matches values less than or equal to the bound.
+    le => LessThanOrEqualTo, PartialOrd, <=, "less than or equal to";
+    /// Matches values greater than the bound.
+    gt => GreaterThan, PartialOrd, >, "greater than";
+    /// Matches values greater than or equal to the bound.
+    ge => GreaterThanOrEqualTo, PartialOrd, >=, "greater than or equal to";
+}
+
+/// The matcher returned by [`not`].
+#[derive(Clone, Copy, Debug)]
+pub struct Not<M>(M);
+
+/// Matches values not matching the matcher.
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not(matcher)
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    #[inline]
+    fn matches(&self, actual: &T) -> bool {
+        !self.0.matches(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not ")?;
+        self.0.describe(f)
+    }
+}
+
+/// The matcher returned by [`all_of`].
+#[derive(Clone, Copy, Debug)]
+pub struct AllOf<L>(L);
+
+/// Matches values matching all matchers in the tuple.
+pub fn all_of<L>(matchers: L) -> AllOf<L> {
+    AllOf(matchers)
+}
+
+impl<T: ?Sized, L: MatcherList<T>> Matcher<T> for AllOf<L> {
+    #[inline]
+    fn matches(&self, actual: &T) -> bool {
+        self.0.matches_all(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("all of (")?;
+        self.0.describe_each(f)?;
+        f.write_str(")")
+    }
+}
+
+/// The matcher returned by [`any_of`].
+#[derive(Clone, Copy, Debug)]
+pub struct AnyOf<L>(L);
+
+/// Matches values matching any matcher in the tuple.
+pub fn any_of<L>(matchers: L) -> AnyOf<L> {
+    AnyOf(matchers)
+}
+
+impl<T: ?Sized, L: MatcherList<T>> Matcher<T> for AnyOf<L> {
+    #[inline]
+    fn matches(&self, actual: &T) -> bool {
+        self.0.matches_any(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any of (")?;
+        self.0.describe_each(f)?;
+        f.write_str(")")
+    }
+}
+
+/// The matcher returned by [`contains`].
+#[derive(Clone, Copy, Debug)]
+pub struct Contains<M>(M);
+
+/// Matches slices, arrays and vectors containing an item matching the matcher.
+pub fn contains<M>(matcher: M) -> Contains<M> {
+    Contains(matcher)
+}
+
+macro_rules! impl_contains_matcher {
+    ($([$($gen:tt)*] $ty:ty;)+) => {
+        $(
+            impl<$($gen)*, M: Matcher<I>> Matcher<$ty> for Contains<M> {
+                #[inline]
+                fn matches(&self, actual: &$ty) -> bool {
+                    actual.iter().any(|item| self.0.matches(item))
+                }
+
+                fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("containing ")?;
+                    self.0.describe(f)
+                }
+            }
+        )+
+    };
+}
+
+impl_contains_matcher! {
+    [I] [I];
+    ['a, I] &'a [I];
+    [I, const N: usize] [I; N];
+}
+
+#[cfg(feature = "alloc")]
+impl_contains_matcher! {
+    [I] alloc::vec::Vec<I>;
+    ['a, I] &'a alloc::vec::Vec<I>;
+}
+
+/// The matcher returned by [`some`].
+#[derive(Clone, Copy, Debug)]
+pub struct SomeMatching<M>(M);
+
+/// Matches [`Some`] with the value matching the matcher.
+pub fn some<M>(matcher: M) -> SomeMatching<M> {
+    SomeMatching(matcher)
+}
+
+impl<T, M: Matcher<T>> Matcher<Option<T>> for SomeMatching<M> {
+    #[inline]
+    fn matches(&self, actual: &Option<T>) -> bool {
+        matches!(*actual, Some(ref v) if self.0.matches(v))
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Some(")?;
+        self.0.describe(f)?;
+        f.write_str(")")
+    }
+}
+
+/// The matcher returned by [`ok`].
+#[derive(Clone, Copy, Debug)]
+pub struct OkMatching<M>(M);
+
+/// Matches [`Ok`] with the value matching the matcher.
+pub fn ok<M>(matcher: M) -> OkMatching<M> {
+    OkMatching(matcher)
+}
+
+impl<T, E, M: Matcher<T>> Matcher<Result<T, E>> for OkMatching<M> {
+    #[inline]
+    fn matches(&self, actual: &Result<T, E>) -> bool {
+        matches!(*actual, Ok(ref v) if self.0.matches(v))
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ok(")?;
+        self.0.describe(f)?;
+        f.write_str(")")
+    }
+}
+
+/// The matcher returned by [`err`].
+#[derive(Clone, Copy, Debug)]
+pub struct ErrMatching<M>(M);
+
+/// Matches [`Err`] with the error matching the matcher.
+pub fn err<M>(matcher: M) -> ErrMatching<M> {
+    ErrMatching(matcher)
+}
+
+impl<T, E, M: Matcher<E>> Matcher<Result<T, E>> for ErrMatching<M> {
+    #[inline]
+    fn matches(&self, actual: &Result<T, E>) -> bool {
+        matches!(*actual, Err(ref e) if self.0.matches(e))
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Err(")?;
+        self.0.describe(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Renders the description of the matcher by [`Matcher::describe`].
+pub(crate) struct Description<'a, M: ?Sized, T: ?Sized> {
+    matcher: &'a M,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<'a, M, T> Description<'a, M, T>
+where
+    M: ?Sized + Matcher<T>,
+    T: ?Sized,
+{
+    pub(crate) fn new(matcher: &'a M) -> Self {
+        Self {
+            matcher,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M, T> fmt::Debug for Description<'_, M, T>
+where
+    M: ?Sized + Matcher<T>,
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.matcher.describe(f)
+    }
+}
+
+/// An extension trait to add the assertion_that_matches methods.
+pub trait AssertMatchesExt: Sized {
+    /// Asserts the value matches the matcher.
+    ///
+    /// # Panics
+    ///
+    /// If the value does not match, the method panics with the description of the matcher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::{any_of, eq, gt};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 42.assert_that_matches(any_of((eq(0), gt(10)))) / 2;
+    /// assert_eq!(x, 21);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::matcher::{contains, eq, not};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = [1, 2, 3].assert_that_matches(not(contains(eq(2))));
+    /// //                ^-- panics here
+    /// ```
+    fn assert_that_matches<M: Matcher<Self>>(self, matcher: M) -> Self;
+
+    /// Asserts the value matches the matcher only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The value does not match
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::{le, some};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = Some(21).debug_assert_that_matches(some(le(21))).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_that_matches<M: Matcher<Self>>(self, matcher: M) -> Self;
+}
+
+#[track_caller]
+#[inline]
+fn check_matches<T, M>(actual: &T, matcher: &M)
+where
+    T: crate::Printable,
+    M: Matcher<T>,
+{
+    if !matcher.matches(actual) {
+        let expected = Description::new(matcher);
+        fail!(NotMatched, "Expected {:?}, got {:?}", expected, actual);
+    }
+}
+
+impl<T: crate::Printable> AssertMatchesExt for T {
+    #[track_caller]
+    #[inline]
+    fn assert_that_matches<M: Matcher<Self>>(self, matcher: M) -> Self {
+        check_matches(&self, &matcher);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_that_matches<M: Matcher<Self>>(self, _matcher: M) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_matches(&self, &_matcher);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod matchers {
+        use super::super::*;
+
+        #[test]
+        fn it_compares_values() {
+            assert!(eq(1).matches(&1));
+            assert!(!eq(1).matches(&2));
+            assert!(ne(1).matches(&2));
+            assert!(lt(1).matches(&0));
+            assert!(le(1).matches(&1));
+            assert!(gt(1).matches(&2));
+            assert!(ge(1).matches(&1));
+            assert!(!ge(1).matches(&0));
+        }
+
+        #[test]
+        fn it_combines_matchers() {
+            assert!(all_of((gt(0), lt(10))).matches(&5));
+            assert!(!all_of((gt(0), lt(10))).matches(&10));
+            assert!(any_of((eq(0), gt(10))).matches(&0));
+            assert!(!any_of((eq(0), gt(10))).matches(&5));
+            assert!(not(eq(0)).matches(&5));
+            assert!(anything().matches(&5));
+        }
+
+        #[test]
+        fn it_matches_containers() {
+            assert!(contains(eq(2)).matches(&[1, 2, 3]));
+            assert!(!contains(eq(4)).matches(&[1, 2, 3][..]));
+            assert!(some(eq(1)).matches(&Some(1)));
+            assert!(!some(anything()).matches(&None::<i32>));
+            assert!(ok(eq(1)).matches(&Ok::<i32, ()>(1)));
+            assert!(!ok(anything()).matches(&Err::<i32, i32>(1)));
+            assert!(err(eq(1)).matches(&Err::<(), i32>(1)));
+            assert!(!err(anything()).matches(&Ok::<i32, i32>(1)));
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn it_describes_nested_matchers() {
+            let matcher = ok(all_of((gt(0), not(any_of((eq(5), ge(10)))))));
+            let description = Description::<_, Result<i32, ()>>::new(&matcher);
+            assert_eq!(
+                alloc::format!("{:?}", description),
+                "Ok(all of (greater than 0, not any of (equal to 5, greater than or equal to 10)))"
+            );
+        }
+    }
+
+    mod assert_that_matches {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x = 42.assert_that_matches(all_of((gt(0), ne(41)))) / 2;
+            assert_eq!(x, 21);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(all of (greater than 0, less than 10)), got Ok(12)")]
+        fn it_fails_on_nested_mismatch() {
+            let x: Result<i32, &str> = Ok(12);
+            let _ = x.assert_that_matches(ok(all_of((gt(0), lt(10)))));
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(containing equal to 4), got Some([1, 2, 3])")]
+        fn it_fails_on_missing_item() {
+            let x = Some([1, 2, 3]);
+            let _ = x.assert_that_matches(some(contains(eq(4))));
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_that_matches {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x: Result<(), &str> = Err("error");
+            let x = x.debug_assert_that_matches(err(eq("error")));
+            assert_eq!(x, Err("error"));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Err(anything), got Ok(1)")
+        )]
+        fn it_fails_on_mismatch() {
+            let x: Result<i32, &str> = Ok(1);
+            let x = x.debug_assert_that_matches(err(anything()));
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok(1));
+        }
+    }
+}
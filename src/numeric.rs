@@ -0,0 +1,417 @@
+//! Assertions for primitive numbers.
+
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
+
+/// An extension trait to add the assertion_in_range methods.
+pub trait AssertInRangeExt: Sized {
+    /// Asserts the number is in the range.
+    ///
+    /// # Panics
+    ///
+    /// If the number is out of the range, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.assert_in_range(0..=100) * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 101.assert_in_range(0..=100) * 2;
+    /// //          ^-- panics here
+    /// ```
+    fn assert_in_range<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<Self> + crate::Printable;
+
+    /// Asserts the number is in the range only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number is out of the range
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 0.5.debug_assert_in_range(0.0..1.0) * 2.0;
+    /// assert_eq!(x, 1.0);
+    /// ```
+    fn debug_assert_in_range<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<Self> + crate::Printable;
+}
+
+/// An extension trait to add the assertion_positive methods.
+pub trait AssertPositiveExt {
+    /// Asserts the number is greater than zero.
+    ///
+    /// # Panics
+    ///
+    /// If the number is zero, negative or NaN, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.assert_positive() * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = (-21).assert_positive() * 2;
+    /// //            ^-- panics here
+    /// ```
+    fn assert_positive(self) -> Self;
+
+    /// Asserts the number is greater than zero only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number is zero, negative or NaN
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 21.debug_assert_positive() * 2;
+    /// assert_eq!(x, 42);
+    /// ```
+    fn debug_assert_positive(self) -> Self;
+}
+
+/// An extension trait to add the assertion_non_zero methods.
+pub trait AssertNonZeroExt {
+    /// Asserts the number is not zero.
+    ///
+    /// # Panics
+    ///
+    /// If the number is zero, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 84 / 2.assert_non_zero();
+    /// assert_eq!(x, 42);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 0.assert_non_zero();
+    /// //        ^-- panics here
+    /// ```
+    fn assert_non_zero(self) -> Self;
+
+    /// Asserts the number is not zero only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The number is zero
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 84 / 2.debug_assert_non_zero();
+    /// assert_eq!(x, 42);
+    /// ```
+    fn debug_assert_non_zero(self) -> Self;
+}
+
+macro_rules! impl_numeric_assertions {
+    ($zero:expr => $($ty:ty),+) => {
+        $(
+            impl AssertInRangeExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_in_range<R>(self, range: R) -> Self
+                where
+                    R: RangeBounds<Self> + crate::Printable,
+                {
+                    if !range.contains(&self) {
+                        let value = self;
+                        fail!(OutOfRange, "Expected a value in {:?}, got {:?}", range, value);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_in_range<R>(self, _range: R) -> Self
+                where
+                    R: RangeBounds<Self> + crate::Printable,
+                {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        if !_range.contains(&self) {
+                            let value = self;
+                            fail!(OutOfRange, "Expected a value in {:?}, got {:?}", _range, value);
+                        }
+                    }
+                    self
+                }
+            }
+
+            impl AssertPositiveExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_positive(self) -> Self {
+                    if self.partial_cmp(&$zero) != Some(Ordering::Greater) {
+                        let value = self;
+                        fail!(NotPositive, "Expected a positive value, got {:?}", value);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_positive(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        if self.partial_cmp(&$zero) != Some(Ordering::Greater) {
+                            let value = self;
+                            fail!(NotPositive, "Expected a positive value, got {:?}", value);
+                        }
+                    }
+                    self
+                }
+            }
+
+            impl AssertNonZeroExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_non_zero(self) -> Self {
+                    if self == $zero {
+                        let value = self;
+                        fail!(Zero, "Expected a non-zero value, got {:?}", value);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_non_zero(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        if self == $zero {
+                            let value = self;
+                            fail!(Zero, "Expected a non-zero value, got {:?}", value);
+                        }
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_numeric_assertions!(0 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_numeric_assertions!(0.0 => f32, f64);
+
+#[cfg(test)]
+mod tests {
+    mod assert_in_range {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x = 21.assert_in_range(0..=100) * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        fn it_succeeds_on_inclusive_end() {
+            let x = 100u8.assert_in_range(..=100);
+            assert_eq!(x, 100);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value in 0..100, got 100")]
+        fn it_fails_on_exclusive_end() {
+            let _ = 100.assert_in_range(0..100);
+            //          ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a value in 0.0..=1.0, got NaN")]
+        fn it_fails_on_nan() {
+            let _ = f64::NAN.assert_in_range(0.0..=1.0);
+            //               ^-- should panic here
+        }
+    }
+
+    mod debug_assert_in_range {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x = 0.5f32.debug_assert_in_range(0.0..1.0) * 2.0;
+            assert_eq!(x, 1.0);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a value in -10..=10, got -11")
+        )]
+        fn it_fails_out_of_range() {
+            let x = (-11i64).debug_assert_in_range(-10..=10);
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, -11);
+        }
+    }
+
+    mod assert_positive {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_positive() {
+            let x = 21.assert_positive() * 2;
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a positive value, got 0")]
+        fn it_fails_on_zero() {
+            let _ = 0u32.assert_positive();
+            //           ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a positive value, got -0.5")]
+        fn it_fails_on_negative() {
+            let _ = (-0.5f32).assert_positive();
+            //                ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a positive value, got NaN")]
+        fn it_fails_on_nan() {
+            let _ = f64::NAN.assert_positive();
+            //               ^-- should panic here
+        }
+    }
+
+    mod debug_assert_positive {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_positive() {
+            let x = 0.5.debug_assert_positive() * 2.0;
+            assert_eq!(x, 1.0);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a positive value, got -1")
+        )]
+        fn it_fails_on_negative() {
+            let x = (-1i8).debug_assert_positive();
+            //             ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, -1);
+        }
+    }
+
+    mod assert_non_zero {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_non_zero() {
+            let x = 84 / 2.assert_non_zero();
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a non-zero value, got 0")]
+        fn it_fails_on_zero() {
+            let _ = 0usize.assert_non_zero();
+            //             ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a non-zero value, got -0.0")]
+        fn it_fails_on_negative_zero() {
+            let _ = (-0.0f64).assert_non_zero();
+            //                ^-- should panic here
+        }
+    }
+
+    mod debug_assert_non_zero {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_non_zero() {
+            let x = 84 / 2.debug_assert_non_zero();
+            assert_eq!(x, 42);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a non-zero value, got 0")
+        )]
+        fn it_fails_on_zero() {
+            let x = 0i128.debug_assert_non_zero();
+            //            ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, 0);
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use crate::matcher::{Description, Matcher};
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+
 /// An extension trait to add the assertion_some methods.
 pub trait AssertSomeExt {
     /// Asserts the [`Option`] is [`Some`].
@@ -31,7 +35,7 @@ pub trait AssertSomeExt {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`None`]
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method returns self as is.
@@ -82,7 +86,7 @@ pub trait AssertSomeAndExt<T> {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`None`] or the condition is not satisfied
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method returns self as is.
@@ -141,7 +145,7 @@ pub trait AssertNoneExt {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Some`]
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method returns self as is.
@@ -158,81 +162,1320 @@ pub trait AssertNoneExt {
     fn debug_assert_none(self) -> Self;
 }
 
-impl<T> AssertSomeExt for Option<T> {
-    #[track_caller]
-    #[inline]
-    fn assert_some(self) -> Self {
-        if self.is_none() {
-            panic!("Expected Some(_), got None");
-        }
-        self
-    }
+/// An extension trait to add the assertion_some_in methods.
+pub trait AssertSomeInExt<T> {
+    /// Asserts the [`Option`] is [`Some`] and its value is in the range.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is out of the range, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.assert_some_in(0..=100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(101);
+    /// let _ = x.assert_some_in(0..=100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is in the range only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is out of the range
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_in(0..=100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable;
+}
+
+/// An extension trait to add the assertion_some_comparison methods.
+pub trait AssertSomeCmpExt<T> {
+    /// Asserts the [`Option`] is [`Some`] and its value is less than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is not less than the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.assert_some_lt(&100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(100);
+    /// let _ = x.assert_some_lt(&100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is less than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is not less than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_lt(&100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is less than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is not less than or equal to the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(100);
+    /// let x = x.assert_some_le(&100).map(|x| x * 2);
+    /// assert_eq!(x, Some(200));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(101);
+    /// let _ = x.assert_some_le(&100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is less than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is not less than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(100);
+    /// let x = x.debug_assert_some_le(&100).map(|x| x * 2);
+    /// assert_eq!(x, Some(200));
+    /// ```
+    fn debug_assert_some_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is greater than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is not greater than the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.assert_some_gt(&0).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(0);
+    /// let _ = x.assert_some_gt(&0).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is greater than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is not greater than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_gt(&0).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is greater than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is not greater than or equal to the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(0);
+    /// let x = x.assert_some_ge(&0).map(|x| x * 2);
+    /// assert_eq!(x, Some(0));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(-1);
+    /// let _ = x.assert_some_ge(&0).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is greater than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is not greater than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(0);
+    /// let x = x.debug_assert_some_ge(&0).map(|x| x * 2);
+    /// assert_eq!(x, Some(0));
+    /// ```
+    fn debug_assert_some_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is between `lo` and `hi`, both inclusive.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value is not between `lo` and `hi`, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.assert_some_between(&0, &100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(101);
+    /// let _ = x.assert_some_between(&0, &100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Option`] is [`Some`] and its value is between `lo` and `hi`, both inclusive only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value is not between `lo` and `hi`
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_between(&0, &100).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+}
+
+/// An extension trait to add the assertion_some_matching methods.
+pub trait AssertSomeMatchingExt<T> {
+    /// Asserts the [`Option`] is [`Some`] and its value matches the matcher.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`] or the value does not match, the method panics with the description
+    /// of the matcher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::{all_of, gt, lt};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(5);
+    /// let x = x.assert_some_matching(all_of((gt(0), lt(10)))).map(|x| x * 2);
+    /// assert_eq!(x, Some(10));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::matcher::{all_of, gt, lt};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(12);
+    /// let _ = x.assert_some_matching(all_of((gt(0), lt(10))));
+    /// //        ^-- panics here
+    /// ```
+    fn assert_some_matching<M: Matcher<T>>(self, matcher: M) -> Self;
+
+    /// Asserts the [`Option`] is [`Some`] and its value matches the matcher only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`] or the value does not match
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::eq;
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_matching(eq(21)).map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_matching<M: Matcher<T>>(self, matcher: M) -> Self;
+}
+
+/// An extension trait to add the assertion_some_labeled methods.
+pub trait AssertSomeLabeledExt {
+    /// Asserts the [`Option`] is [`Some`], naming the step of the chain in the failure message.
+    ///
+    /// The label tells apart the steps sharing a line in a long chain.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`None`], the method panics with the label.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "level=3".split_once('=').assert_some_labeled("split").map(|(_, v)| v);
+    /// assert_eq!(x, Some("3"));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "level".split_once('=').assert_some_labeled("split");
+    /// //                              ^-- panics here
+    /// ```
+    fn assert_some_labeled(self, label: &str) -> Self;
+
+    /// Asserts the [`Option`] is [`Some`], naming the step of the chain, only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = x.debug_assert_some_labeled("lookup").map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_assert_some_labeled(self, label: &str) -> Self;
+}
+
+/// An extension trait to add the debug_inspect_some methods.
+pub trait DebugInspectSomeExt<T> {
+    /// Calls the function with the value if it is [`Some`] only in debug builds.
+    ///
+    /// The function is called if all following conditions are satisfied:
+    ///
+    /// - It is [`Some`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = Some(21)
+    ///     .debug_inspect_some(|x| println!("found {}", x))
+    ///     .map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_inspect_some<F: FnOnce(&T)>(self, f: F) -> Self;
+}
+
+/// An extension trait to add the assume_some methods.
+pub trait AssumeSomeExt {
+    /// Assumes the [`Option`] is [`Some`] and lets the optimizer rely on it in release builds.
+    ///
+    /// The assumption is checked whenever `debug_assertions` or `always-check` feature is enabled.
+    /// Unlike `debug_assert_some`, `passthrough` feature does not disable the check, since it may be enabled
+    /// by another crate in the dependency graph and silently turn the check into undefined behavior.
+    /// When the check is disabled, the failing branch becomes unreachable for the optimizer,
+    /// so it can elide the following checks like `unwrap` in the chain.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`None`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    ///
+    /// # Safety
+    ///
+    /// It must be [`Some`]. Calling this method on [`None`] is *undefined behavior*
+    /// when neither `debug_assertions` nor `always-check` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = Some(21);
+    /// let x = unsafe { x.assume_some() }.map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    unsafe fn assume_some(self) -> Self;
+}
+
+/// An extension trait to add the assume_none methods.
+pub trait AssumeNoneExt {
+    /// Assumes the [`Option`] is [`None`] and lets the optimizer rely on it in release builds.
+    ///
+    /// The assumption is checked whenever `debug_assertions` or `always-check` feature is enabled.
+    /// Unlike `debug_assert_none`, `passthrough` feature does not disable the check, since it may be enabled
+    /// by another crate in the dependency graph and silently turn the check into undefined behavior.
+    /// When the check is disabled, the failing branch becomes unreachable for the optimizer,
+    /// so it can elide the following checks like `unwrap` in the chain.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Some`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    ///
+    /// # Safety
+    ///
+    /// It must be [`None`]. Calling this method on [`Some`] is *undefined behavior*
+    /// when neither `debug_assertions` nor `always-check` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Option<i32> = None;
+    /// let x = unsafe { x.assume_none() }.map(|x| x * 2);
+    /// assert_eq!(x, None);
+    /// ```
+    unsafe fn assume_none(self) -> Self;
+}
+
+impl<T> AssertSomeExt for &Option<T> {
+    #[track_caller]
+    #[inline]
+    fn assert_some(self) -> Self {
+        if self.is_none() {
+            fail!(ExpectedSome, "Expected Some(_), got None");
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if self.is_none() {
+                fail!(ExpectedSome, "Expected Some(_), got None");
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertSomeAndExt<T> for &Option<T>
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_some_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+        match self {
+            Some(ref v) if cond(v) => { /* do nothing */ }
+            Some(ref v) => fail!(
+                ConditionNotSatisfied,
+                "Condition not satisfied for Some({:?})",
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_and(self, _cond: impl FnOnce(&T) -> bool) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if _cond(v) => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    ConditionNotSatisfied,
+                    "Condition not satisfied for Some({:?})",
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertNoneExt for &Option<T>
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_none(self) -> Self {
+        if let Some(ref v) = self {
+            fail!(ExpectedNone, "Expected None, got Some({:?})", v);
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_none(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Some(ref v) = self {
+                fail!(ExpectedNone, "Expected None, got Some({:?})", v);
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertSomeInExt<T> for &Option<T>
+where
+    T: PartialOrd + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_some_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable,
+    {
+        match self {
+            Some(ref v) if range.contains(v) => { /* do nothing */ }
+            Some(ref v) => fail!(
+                OutOfRange,
+                "Expected Some(_) in {:?}, got Some({:?})",
+                range,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_in<R>(self, _range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if _range.contains(v) => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    OutOfRange,
+                    "Expected Some(_) in {:?}, got Some({:?})",
+                    _range,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertSomeCmpExt<T> for &Option<T>
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_some_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Some(ref v) if *v < *bound => { /* do nothing */ }
+            Some(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Some(_) less than {:?}, got Some({:?})",
+                bound,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_lt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if *v < *_bound => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Some(_) less than {:?}, got Some({:?})",
+                    _bound,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_some_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Some(ref v) if *v <= *bound => { /* do nothing */ }
+            Some(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Some(_) less than or equal to {:?}, got Some({:?})",
+                bound,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_le<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if *v <= *_bound => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Some(_) less than or equal to {:?}, got Some({:?})",
+                    _bound,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_some_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Some(ref v) if *v > *bound => { /* do nothing */ }
+            Some(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Some(_) greater than {:?}, got Some({:?})",
+                bound,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_gt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if *v > *_bound => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Some(_) greater than {:?}, got Some({:?})",
+                    _bound,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_some_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Some(ref v) if *v >= *bound => { /* do nothing */ }
+            Some(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Some(_) greater than or equal to {:?}, got Some({:?})",
+                bound,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_ge<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if *v >= *_bound => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Some(_) greater than or equal to {:?}, got Some({:?})",
+                    _bound,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_some_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Some(ref v) if *v >= *lo && *v <= *hi => { /* do nothing */ }
+            Some(ref v) => fail!(
+                OutOfRange,
+                "Expected Some(_) between {:?} and {:?}, got Some({:?})",
+                lo,
+                hi,
+                v
+            ),
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_between<B>(self, _lo: &B, _hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if *v >= *_lo && *v <= *_hi => { /* do nothing */ }
+                Some(ref v) => fail!(
+                    OutOfRange,
+                    "Expected Some(_) between {:?} and {:?}, got Some({:?})",
+                    _lo,
+                    _hi,
+                    v
+                ),
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertSomeMatchingExt<T> for &Option<T>
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_some_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+        match self {
+            Some(ref v) if matcher.matches(v) => { /* do nothing */ }
+            Some(ref v) => {
+                let expected = Description::new(&matcher);
+                fail!(
+                    NotMatched,
+                    "Expected Some({:?}), got Some({:?})",
+                    expected,
+                    v
+                )
+            }
+            None => fail!(ExpectedSome, "Expected Some(_), got None"),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_matching<M: Matcher<T>>(self, _matcher: M) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Some(ref v) if _matcher.matches(v) => { /* do nothing */ }
+                Some(ref v) => {
+                    let expected = Description::new(&_matcher);
+                    fail!(
+                        NotMatched,
+                        "Expected Some({:?}), got Some({:?})",
+                        expected,
+                        v
+                    )
+                }
+                None => fail!(ExpectedSome, "Expected Some(_), got None"),
+            }
+        }
+        self
+    }
+}
+
+impl<T> AssertSomeLabeledExt for &Option<T> {
+    #[track_caller]
+    #[inline]
+    fn assert_some_labeled(self, label: &str) -> Self {
+        if self.is_none() {
+            fail!(ExpectedSome, "Expected Some(_) from {:?}, got None", label);
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_some_labeled(self, _label: &str) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if self.is_none() {
+                let label = _label;
+                fail!(ExpectedSome, "Expected Some(_) from {:?}, got None", label);
+            }
+        }
+        self
+    }
+}
+
+/// Forwards the assertions for [`Option`] and the mutable references to it to the implementation
+/// for the shared reference, so the failure paths are not duplicated for each receiver.
+macro_rules! forward_option_assertions {
+    ($([$($gen:tt)*] $ty:ty;)+) => {
+        $(
+            impl<$($gen)*> AssertSomeExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_some(self) -> Self {
+                    AssertSomeExt::assert_some(Borrow::<Option<T>>::borrow(&self));
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some(self) -> Self {
+                    AssertSomeExt::debug_assert_some(Borrow::<Option<T>>::borrow(&self));
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertSomeAndExt<T> for $ty
+            where
+                T: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_some_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+                    AssertSomeAndExt::assert_some_and(Borrow::<Option<T>>::borrow(&self), cond);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+                    AssertSomeAndExt::debug_assert_some_and(
+                        Borrow::<Option<T>>::borrow(&self),
+                        cond,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertNoneExt for $ty
+            where
+                T: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_none(self) -> Self {
+                    AssertNoneExt::assert_none(Borrow::<Option<T>>::borrow(&self));
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_none(self) -> Self {
+                    AssertNoneExt::debug_assert_none(Borrow::<Option<T>>::borrow(&self));
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertSomeInExt<T> for $ty
+            where
+                T: PartialOrd + crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_some_in<R>(self, range: R) -> Self
+                where
+                    R: RangeBounds<T> + crate::Printable,
+                {
+                    AssertSomeInExt::assert_some_in(Borrow::<Option<T>>::borrow(&self), range);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_in<R>(self, range: R) -> Self
+                where
+                    R: RangeBounds<T> + crate::Printable,
+                {
+                    AssertSomeInExt::debug_assert_some_in(
+                        Borrow::<Option<T>>::borrow(&self),
+                        range,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertSomeCmpExt<T> for $ty
+            where
+                T: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_some_lt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::assert_some_lt(Borrow::<Option<T>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_lt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::debug_assert_some_lt(
+                        Borrow::<Option<T>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_some_le<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::assert_some_le(Borrow::<Option<T>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_le<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::debug_assert_some_le(
+                        Borrow::<Option<T>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_some_gt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::assert_some_gt(Borrow::<Option<T>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_gt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::debug_assert_some_gt(
+                        Borrow::<Option<T>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_some_ge<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::assert_some_ge(Borrow::<Option<T>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_ge<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::debug_assert_some_ge(
+                        Borrow::<Option<T>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_some_between<B>(self, lo: &B, hi: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::assert_some_between(
+                        Borrow::<Option<T>>::borrow(&self),
+                        lo,
+                        hi,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_between<B>(self, lo: &B, hi: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertSomeCmpExt::debug_assert_some_between(
+                        Borrow::<Option<T>>::borrow(&self),
+                        lo,
+                        hi,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertSomeMatchingExt<T> for $ty
+            where
+                T: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_some_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+                    AssertSomeMatchingExt::assert_some_matching(
+                        Borrow::<Option<T>>::borrow(&self),
+                        matcher,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+                    AssertSomeMatchingExt::debug_assert_some_matching(
+                        Borrow::<Option<T>>::borrow(&self),
+                        matcher,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertSomeLabeledExt for $ty {
+                #[track_caller]
+                #[inline]
+                fn assert_some_labeled(self, label: &str) -> Self {
+                    AssertSomeLabeledExt::assert_some_labeled(
+                        Borrow::<Option<T>>::borrow(&self),
+                        label,
+                    );
+                    self
+                }
 
-    #[track_caller]
-    #[inline]
-    fn debug_assert_some(self) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
-        {
-            if self.is_none() {
-                panic!("Expected Some(_), got None");
+                #[track_caller]
+                #[inline]
+                fn debug_assert_some_labeled(self, label: &str) -> Self {
+                    AssertSomeLabeledExt::debug_assert_some_labeled(
+                        Borrow::<Option<T>>::borrow(&self),
+                        label,
+                    );
+                    self
+                }
             }
-        }
-        self
-    }
+        )+
+    };
 }
 
-impl<T> AssertSomeAndExt<T> for Option<T>
-where
-    T: crate::fmt::Debug,
-{
-    #[track_caller]
+forward_option_assertions! {
+    [T] Option<T>;
+    ['a, T] &'a mut Option<T>;
+}
+
+impl<T> DebugInspectSomeExt<T> for Option<T> {
     #[inline]
-    fn assert_some_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
-        match self {
-            Some(ref v) if cond(v) => { /* do nothing */ }
-            Some(ref v) => panic!("Condition not satisfied for Some({:?})", v),
-            None => panic!("Expected Some(_), got None"),
+    fn debug_inspect_some<F: FnOnce(&T)>(self, _f: F) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Some(ref x) = self {
+                _f(x);
+            }
         }
         self
     }
+}
 
+impl<T> AssumeSomeExt for Option<T> {
     #[track_caller]
     #[inline]
-    fn debug_assert_some_and(self, _cond: impl FnOnce(&T) -> bool) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
+    unsafe fn assume_some(self) -> Self {
+        #[cfg(any(debug_assertions, feature = "always-check"))]
         {
-            match self {
-                Some(ref v) if _cond(v) => { /* do nothing */ }
-                Some(ref v) => panic!("Condition not satisfied for Some({:?})", v),
-                None => panic!("Expected Some(_), got None"),
+            if self.is_none() {
+                fail!(ExpectedSome, "Expected Some(_), got None");
             }
         }
+        if self.is_none() {
+            core::hint::unreachable_unchecked();
+        }
         self
     }
 }
 
-impl<T> AssertNoneExt for Option<T>
+impl<T> AssumeNoneExt for Option<T>
 where
-    T: crate::fmt::Debug,
+    T: crate::Printable,
 {
     #[track_caller]
     #[inline]
-    fn assert_none(self) -> Self {
-        if let Some(ref v) = self {
-            panic!("Expected None, got Some({:?})", v);
-        }
-        self
-    }
-
-    #[track_caller]
-    #[inline]
-    fn debug_assert_none(self) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
+    unsafe fn assume_none(self) -> Self {
+        #[cfg(any(debug_assertions, feature = "always-check"))]
         {
             if let Some(ref v) = self {
-                panic!("Expected None, got Some({:?})", v);
+                fail!(ExpectedNone, "Expected None, got Some({:?})", v);
             }
         }
+        if self.is_some() {
+            core::hint::unreachable_unchecked();
+        }
         self
     }
 }
@@ -311,9 +1554,13 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic(expected = "Expected Some(_), got None")
         )]
+        #[allow(clippy::redundant_pattern_matching)]
         fn it_fails_on_none() {
             let x: Option<NonDebuggable> = None;
             let x = x.debug_assert_some();
@@ -335,7 +1582,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic(expected = "Condition not satisfied for Some(19)")
         )]
         fn it_fails_on_some_and_condition_not_satisfied() {
@@ -349,7 +1599,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic(expected = "Expected Some(_), got None")
         )]
         fn it_fails_on_none() {
@@ -366,6 +1619,7 @@ mod tests {
         use super::{super::*, *};
 
         #[test]
+        #[allow(clippy::redundant_pattern_matching)]
         fn it_succeeds_on_none() {
             let x: Option<Debuggable> = None;
             let x = x.assert_none();
@@ -386,6 +1640,7 @@ mod tests {
         use super::{super::*, *};
 
         #[test]
+        #[allow(clippy::redundant_pattern_matching)]
         fn it_succeeds_on_none() {
             let x: Option<Debuggable> = None;
             let x = x.debug_assert_none();
@@ -395,7 +1650,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic(expected = "Expected None, got Some(Debuggable)")
         )]
         fn it_fails_on_some() {
@@ -407,4 +1665,413 @@ mod tests {
             assert!(matches!(x, Some(Debuggable)), "Expected Some(Debuggable)");
         }
     }
+
+    mod assume_some {
+        use super::super::*;
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        use super::NonDebuggable;
+
+        #[test]
+        fn it_succeeds_on_some() {
+            let x: Option<i32> = Some(21);
+            let x = unsafe { x.assume_some() }.map(|x| x * 2);
+
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        #[should_panic(expected = "Expected Some(_), got None")]
+        fn it_fails_on_none_only_if_checked() {
+            let x: Option<NonDebuggable> = None;
+            let _ = unsafe { x.assume_some() };
+            //               ^-- should panic here only if checked
+        }
+    }
+
+    mod assume_none {
+        use super::super::*;
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        use super::Debuggable;
+
+        #[test]
+        fn it_succeeds_on_none() {
+            let x: Option<i32> = None;
+            let x = unsafe { x.assume_none() }.map(|x| x * 2);
+
+            assert_eq!(x, None);
+        }
+
+        #[test]
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        #[should_panic(expected = "Expected None, got Some(Debuggable)")]
+        fn it_fails_on_some_only_if_checked() {
+            let x: Option<Debuggable> = Some(Debuggable);
+            let _ = unsafe { x.assume_none() };
+            //               ^-- should panic here only if checked
+        }
+    }
+
+    mod assert_some_in {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x: Option<i32> = Some(21);
+            let x = x.assert_some_in(0..=100).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_) in 0..=100, got Some(101)")]
+        fn it_fails_out_of_range() {
+            let x: Option<i32> = Some(101);
+            let _ = x.assert_some_in(0..=100).map(|x| x * 2);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_), got None")]
+        fn it_fails_on_none() {
+            let x: Option<i32> = None;
+            let _ = x.assert_some_in(0..=100).map(|x| x * 2);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_some_in {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x: Option<i32> = Some(21);
+            let x = x.debug_assert_some_in(0..=100).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Some(_) in 0..=100, got Some(101)")
+        )]
+        fn it_fails_out_of_range() {
+            let x: Option<i32> = Some(101);
+            let x = x.debug_assert_some_in(0..=100);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Some(101));
+        }
+    }
+
+    mod assert_some_lt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_less() {
+            let x: Option<i32> = Some(21);
+            let x = x.assert_some_lt(&100).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_) less than 100, got Some(100)")]
+        fn it_fails_on_equal() {
+            let x: Option<i32> = Some(100);
+            let _ = x.assert_some_lt(&100);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_), got None")]
+        fn it_fails_on_none() {
+            let x: Option<i32> = None;
+            let _ = x.assert_some_lt(&100);
+            //        ^-- should panic here
+        }
+    }
+
+    mod assert_some_ge {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_equal() {
+            let x: Option<i32> = Some(0);
+            let x = x.assert_some_ge(&0);
+            assert_eq!(x, Some(0));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_) greater than or equal to 0, got Some(-1)")]
+        fn it_fails_on_less() {
+            let x: Option<i32> = Some(-1);
+            let _ = x.assert_some_ge(&0);
+            //        ^-- should panic here
+        }
+    }
+
+    mod assert_some_between {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_between_bounds() {
+            let x: Option<i32> = Some(21);
+            let x = x.assert_some_between(&0, &100).map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_) between 0 and 100, got Some(101)")]
+        fn it_fails_out_of_bounds() {
+            let x: Option<i32> = Some(101);
+            let _ = x.assert_some_between(&0, &100);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_some_gt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_greater() {
+            let x: Option<i32> = Some(1);
+            let x = x.debug_assert_some_gt(&0);
+            assert_eq!(x, Some(1));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Some(_) greater than 0, got Some(0)")
+        )]
+        fn it_fails_on_equal() {
+            let x: Option<i32> = Some(0);
+            let x = x.debug_assert_some_gt(&0);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Some(0));
+        }
+    }
+
+    mod assert_some_matching {
+        use super::super::*;
+        use crate::matcher::{all_of, eq, gt, lt};
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x: Option<i32> = Some(21);
+            let x = x
+                .assert_some_matching(all_of((gt(20), lt(22))))
+                .map(|x| x * 2);
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected Some(all of (greater than 0, less than 10)), got Some(12)"
+        )]
+        fn it_fails_on_mismatch() {
+            let x: Option<i32> = Some(12);
+            let _ = x.assert_some_matching(all_of((gt(0), lt(10))));
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_), got None")]
+        fn it_fails_on_none() {
+            let x: Option<i32> = None;
+            let _ = x.assert_some_matching(eq(21));
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_some_matching {
+        use super::super::*;
+        use crate::matcher::{eq, not};
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x: Option<i32> = Some(21);
+            let x = x.debug_assert_some_matching(eq(21));
+            assert_eq!(x, Some(21));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Some(not equal to 21), got Some(21)")
+        )]
+        fn it_fails_on_mismatch() {
+            let x: Option<i32> = Some(21);
+            let x = x.debug_assert_some_matching(not(eq(21)));
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Some(21));
+        }
+    }
+
+    mod assert_some_labeled {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_some() {
+            let x: Option<NonDebuggable> = Some(NonDebuggable);
+            let x = x.assert_some_labeled("lookup");
+
+            assert!(
+                matches!(x, Some(NonDebuggable)),
+                "Expected Some(NonDebuggable)"
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_) from \"value\", got None")]
+        fn it_fails_on_none() {
+            let _ = "level=x"
+                .split_once('=')
+                .assert_some_labeled("split")
+                .and_then(|(_, v)| v.parse::<i32>().ok())
+                .assert_some_labeled("value");
+            //   ^-- should panic here
+        }
+    }
+
+    mod debug_assert_some_labeled {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_some() {
+            let x: Option<i32> = Some(21);
+            let x = x.debug_assert_some_labeled("lookup");
+            assert_eq!(x, Some(21));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Some(_) from \"lookup\", got None")
+        )]
+        fn it_fails_on_none() {
+            let x: Option<i32> = None;
+            let x = x.debug_assert_some_labeled("lookup");
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, None);
+        }
+    }
+
+    mod debug_inspect_some {
+        use super::super::*;
+
+        #[test]
+        fn it_calls_function_only_in_debug_builds() {
+            let mut inspected = None;
+            let x = Some(21).debug_inspect_some(|x| inspected = Some(*x));
+            assert_eq!(x, Some(21));
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(inspected, if checked { Some(21) } else { None });
+        }
+
+        #[test]
+        fn it_skips_none() {
+            let mut called = false;
+            let x = None::<i32>.debug_inspect_some(|_| called = true);
+            assert_eq!(x, None);
+            assert!(!called, "Expected function not called");
+        }
+    }
+
+    mod borrowed {
+        use super::{super::*, *};
+
+        struct Player {
+            weapon: Option<&'static str>,
+            level: Option<u32>,
+        }
+
+        #[test]
+        fn it_succeeds_on_shared_reference() {
+            let player = Player {
+                weapon: Some("sword"),
+                level: Some(3),
+            };
+            let x = (&player.weapon).assert_some().map(|w| w.len());
+            assert_eq!(x, Some(5));
+
+            let x = (&player.level).assert_some_ge(&1).assert_some_lt(&10);
+            assert_eq!(x, &Some(3));
+        }
+
+        #[test]
+        fn it_succeeds_on_non_debuggable() {
+            let x = Some(NonDebuggable);
+            let x = (&x).assert_some();
+            assert!(matches!(x, Some(NonDebuggable)));
+        }
+
+        #[test]
+        fn it_succeeds_on_mutable_reference() {
+            let mut player = Player {
+                weapon: None,
+                level: Some(3),
+            };
+            *(&mut player.weapon).assert_none() = Some("bow");
+            if let Some(level) = (&mut player.level).assert_some_in(1..10) {
+                *level += 1;
+            }
+            assert_eq!(player.weapon, Some("bow"));
+            assert_eq!(player.level, Some(4));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Some(_), got None")]
+        fn it_fails_on_shared_reference() {
+            let player = Player {
+                weapon: None,
+                level: Some(3),
+            };
+            let _ = (&player.weapon).assert_some_and(|w| !w.is_empty());
+            //                       ^-- should panic here
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected None, got Some(\"sword\")")
+        )]
+        fn it_fails_on_mutable_reference() {
+            let mut player = Player {
+                weapon: Some("sword"),
+                level: None,
+            };
+            let x = (&mut player.weapon).debug_assert_none();
+            //                           ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &mut Some("sword"));
+            assert_eq!(player.level, None);
+        }
+    }
 }
@@ -1,2 +1,34 @@
-pub use crate::option::{AssertNoneExt, AssertSomeAndExt, AssertSomeExt};
-pub use crate::result::{AssertErrAndExt, AssertErrExt, AssertOkAndExt, AssertOkExt};
+pub use crate::bytes::{AssertAlignedToExt, AssertBytesExt};
+pub use crate::checkpoint::{AssertUnchangedByExt, DebugCheckpointExt};
+pub use crate::cmp::{AssertCmpExt, AssertCrossCheckExt};
+#[cfg(feature = "alloc")]
+pub use crate::collection::{AssertCollectExt, AssertContainsKeyExt, AssertNotContainsKeyExt};
+pub use crate::collection::{
+    AssertContainsExt, AssertLenExt, AssertNonEmptyExt, AssertPermutationOfExt,
+    AssertUniqueByKeyExt, AssertUniqueExt,
+};
+#[cfg(feature = "std")]
+pub use crate::collection::{AssertPermutationOfHashedExt, AssertUniqueHashedExt};
+pub use crate::determinism::AssertIdempotentExt;
+#[cfg(feature = "std")]
+pub use crate::failure::AssertionLabelExt;
+pub use crate::float::{AssertApproxEqExt, AssertFiniteExt, AssertNormalizedExt, AssertNotNanExt};
+pub use crate::invariant::{AssertInvariantsExt, Invariant};
+pub use crate::matcher::AssertMatchesExt;
+pub use crate::numeric::{AssertInRangeExt, AssertNonZeroExt, AssertPositiveExt};
+pub use crate::option::{
+    AssertNoneExt, AssertSomeAndExt, AssertSomeCmpExt, AssertSomeExt, AssertSomeInExt,
+    AssertSomeLabeledExt, AssertSomeMatchingExt, AssumeNoneExt, AssumeSomeExt, DebugInspectSomeExt,
+};
+pub use crate::result::{
+    AssertErrAndExt, AssertErrExt, AssertOkAndExt, AssertOkCmpExt, AssertOkEqWithExt, AssertOkExt,
+    AssertOkInExt, AssertOkInvariantsExt, AssertOkLabeledExt, AssertOkMatchingExt, AssumeErrExt,
+    AssumeOkExt, DebugInspectErrExt, DebugInspectOkExt,
+};
+pub use crate::roundtrip::AssertRoundtripExt;
+#[cfg(feature = "serde_json")]
+pub use crate::roundtrip::AssertSerdeRoundtripExt;
+#[cfg(feature = "regex")]
+pub use crate::string::AssertMatchesRegexExt;
+pub use crate::string::AssertStrExt;
+pub use crate::trace::DebugTraceExt;
@@ -0,0 +1,141 @@
+//! Previews printing only the head of large values in failure messages.
+
+use crate::fmt;
+
+/// The maximum number of the entries printed in a list or map preview.
+const MAX_ENTRIES: usize = 8;
+
+/// The maximum number of the characters printed in a string preview.
+const MAX_CHARS: usize = 64;
+
+/// Prints the first entries of the iterator like `[1, 2, ..]`.
+pub(crate) struct ListPreview<I>(pub(crate) I);
+
+/// Prints the first key-value pairs of the iterator like `{1: "a", 2: "b", ..}`.
+#[cfg(feature = "alloc")]
+pub(crate) struct MapPreview<I>(pub(crate) I);
+
+/// Prints the first characters of the string like `"abc"..`.
+pub(crate) struct StrPreview<'a>(pub(crate) &'a str);
+
+impl<'a> StrPreview<'a> {
+    /// Returns the head of the string and whether the rest was dropped.
+    fn head(&self) -> (&'a str, bool) {
+        match self.0.char_indices().nth(MAX_CHARS) {
+            Some((i, _)) => (&self.0[..i], true),
+            None => (self.0, false),
+        }
+    }
+}
+
+impl<I> fmt::Debug for ListPreview<I>
+where
+    I: Iterator + Clone,
+    I::Item: crate::Printable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.0.clone();
+        f.write_str("[")?;
+        for (i, item) in iter.by_ref().take(MAX_ENTRIES).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Debug::fmt(&item, f)?;
+        }
+        if iter.next().is_some() {
+            f.write_str(", ..")?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, K, V> fmt::Debug for MapPreview<I>
+where
+    I: Iterator<Item = (K, V)> + Clone,
+    K: crate::Printable,
+    V: crate::Printable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.0.clone();
+        f.write_str("{")?;
+        for (i, (key, value)) in iter.by_ref().take(MAX_ENTRIES).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}: {:?}", key, value)?;
+        }
+        if iter.next().is_some() {
+            f.write_str(", ..")?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl fmt::Debug for StrPreview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, truncated) = self.head();
+        fmt::Debug::fmt(head, f)?;
+        if truncated {
+            f.write_str("..")?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of the bytes printed before and after the offset in a hex dump.
+const HEX_DUMP_RADIUS: usize = 8;
+
+/// Prints the bytes around the offset in hex like `.. 6c 6c [ff] 6f ..`.
+///
+/// The byte at the offset is bracketed, or `[end]` is printed if the offset is at the end.
+pub(crate) struct HexDump<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    /// Returns the bytes in the window and the offset of the window.
+    fn window(&self) -> (&'a [u8], usize) {
+        let end = self
+            .bytes
+            .len()
+            .min(self.offset.saturating_add(HEX_DUMP_RADIUS));
+        let start = self.offset.saturating_sub(HEX_DUMP_RADIUS).min(end);
+        (&self.bytes[start..end], start)
+    }
+}
+
+/// Prints the address in hex like `0x1000`.
+pub(crate) struct AddressPreview(pub(crate) usize);
+
+impl fmt::Debug for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (window, start) = self.window();
+        if start > 0 {
+            f.write_str(".. ")?;
+        }
+        for (i, byte) in window.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            if start + i == self.offset {
+                write!(f, "[{:02x}]", byte)?;
+            } else {
+                write!(f, "{:02x}", byte)?;
+            }
+        }
+        if self.offset >= self.bytes.len() {
+            f.write_str(if window.is_empty() { "[end]" } else { " [end]" })?;
+        } else if start + window.len() < self.bytes.len() {
+            f.write_str(" ..")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for AddressPreview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
@@ -0,0 +1,9 @@
+/// A value which can be printed in assertion failure messages.
+///
+/// It is implemented for every type implementing [`Debug`](core::fmt::Debug)
+/// in every configuration, so enabling the `defmt` feature does not change which values
+/// can be asserted. With the `defmt` feature, the values are logged through
+/// `defmt::Debug2Format`.
+pub trait Printable: crate::fmt::Debug {}
+
+impl<T> Printable for T where T: crate::fmt::Debug + ?Sized {}
@@ -0,0 +1,280 @@
+//! Not public API, used by the code generated by `chain-assertions-derive`.
+
+use crate::fmt;
+use crate::Printable;
+use core::convert::Infallible;
+use core::ops::ControlFlow;
+
+/// Whether the `debug_assert_*` methods check, decided by the features of this crate.
+pub const DEBUG_CHECK: bool = cfg!(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+));
+
+/// Prints the pattern of a variant, the name of a function or the source of a condition as is.
+struct Pattern(&'static str);
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Fails because the value is not the expected variant.
+#[track_caller]
+#[inline]
+pub fn unexpected_variant<T: ?Sized + Printable>(expected: &'static str, actual: &T) -> ! {
+    let expected = Pattern(expected);
+    fail!(
+        UnexpectedVariant,
+        "Expected {:?}, got {:?}",
+        expected,
+        actual
+    )
+}
+
+/// Fails because the fields of the variant do not satisfy the condition.
+#[track_caller]
+#[inline]
+pub fn condition_not_satisfied<T: ?Sized + Printable>(actual: &T) -> ! {
+    fail!(
+        ConditionNotSatisfied,
+        "Condition not satisfied for {:?}",
+        actual
+    )
+}
+
+/// Fails because the precondition of the function is violated.
+#[track_caller]
+#[inline]
+pub fn precondition_violated(function: &'static str, condition: &'static str) -> ! {
+    let function = Pattern(function);
+    fail!(
+        PreconditionViolated,
+        "Precondition {:?} of {:?} violated",
+        condition,
+        function
+    )
+}
+
+/// Fails because the postcondition of the function is violated.
+#[track_caller]
+#[inline]
+pub fn postcondition_violated(function: &'static str, condition: &'static str) -> ! {
+    let function = Pattern(function);
+    fail!(
+        PostconditionViolated,
+        "Postcondition {:?} of {:?} violated",
+        condition,
+        function
+    )
+}
+
+/// Calls the postcondition with the return value, fixing the type of the closure parameter.
+#[inline]
+pub fn holds_for<T, F>(value: &T, condition: F) -> bool
+where
+    T: ?Sized,
+    F: FnOnce(&T) -> bool,
+{
+    condition(value)
+}
+
+/// Splits the operand of `?` in the body of a `#[contract]` function into the value to continue
+/// with or the residual to return, like the unstable `Try` trait.
+pub trait Branch {
+    type Output;
+    type Residual;
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+impl<T, E> Branch for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Ok(v) => ControlFlow::Continue(v),
+            Err(e) => ControlFlow::Break(Err(e)),
+        }
+    }
+}
+
+impl<T> Branch for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Some(v) => ControlFlow::Continue(v),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+/// Converts the residual of `?` into the return value, like the unstable `FromResidual` trait.
+pub trait FromResidual<R> {
+    fn from_residual(residual: R) -> Self;
+}
+
+impl<T, E, F> FromResidual<Result<Infallible, E>> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Ok(never) => match never {},
+            Err(e) => Err(From::from(e)),
+        }
+    }
+}
+
+impl<T> FromResidual<Option<Infallible>> for Option<T> {
+    #[inline]
+    fn from_residual(_: Option<Infallible>) -> Self {
+        None
+    }
+}
+
+/// Asserts the [`Option`] is [`Some`] and satisfies the condition described by the source.
+#[track_caller]
+#[inline]
+pub fn some_and<T>(
+    value: Option<T>,
+    cond: impl FnOnce(&T) -> bool,
+    source: &'static str,
+) -> Option<T>
+where
+    T: Printable,
+{
+    match value {
+        Some(ref x) if cond(x) => { /* do nothing */ }
+        Some(ref x) => {
+            let source = Pattern(source);
+            fail!(
+                ConditionNotSatisfied,
+                "Condition `{:?}` not satisfied for Some({:?})",
+                source,
+                x
+            )
+        }
+        None => fail!(ExpectedSome, "Expected Some(_), got None"),
+    }
+    value
+}
+
+/// Asserts the [`Result`] is [`Ok`] and satisfies the condition described by the source.
+#[track_caller]
+#[inline]
+pub fn ok_and<T, E>(
+    value: Result<T, E>,
+    cond: impl FnOnce(&T) -> bool,
+    source: &'static str,
+) -> Result<T, E>
+where
+    T: Printable,
+    E: Printable,
+{
+    match value {
+        Ok(ref x) if cond(x) => { /* do nothing */ }
+        Ok(ref x) => {
+            let source = Pattern(source);
+            fail!(
+                ConditionNotSatisfied,
+                "Condition `{:?}` not satisfied for Ok({:?})",
+                source,
+                x
+            )
+        }
+        Err(ref x) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x),
+    }
+    value
+}
+
+/// Asserts the [`Result`] is [`Err`] and satisfies the condition described by the source.
+#[track_caller]
+#[inline]
+pub fn err_and<T, E>(
+    value: Result<T, E>,
+    cond: impl FnOnce(&E) -> bool,
+    source: &'static str,
+) -> Result<T, E>
+where
+    T: Printable,
+    E: Printable,
+{
+    match value {
+        Err(ref x) if cond(x) => { /* do nothing */ }
+        Err(ref x) => {
+            let source = Pattern(source);
+            fail!(
+                ConditionNotSatisfied,
+                "Condition `{:?}` not satisfied for Err({:?})",
+                source,
+                x
+            )
+        }
+        Ok(ref x) => fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x),
+    }
+    value
+}
+
+/// Asserts like [`some_and`] only in debug builds.
+#[track_caller]
+#[inline]
+pub fn debug_some_and<T>(
+    value: Option<T>,
+    cond: impl FnOnce(&T) -> bool,
+    source: &'static str,
+) -> Option<T>
+where
+    T: Printable,
+{
+    if DEBUG_CHECK {
+        some_and(value, cond, source)
+    } else {
+        value
+    }
+}
+
+/// Asserts like [`ok_and`] only in debug builds.
+#[track_caller]
+#[inline]
+pub fn debug_ok_and<T, E>(
+    value: Result<T, E>,
+    cond: impl FnOnce(&T) -> bool,
+    source: &'static str,
+) -> Result<T, E>
+where
+    T: Printable,
+    E: Printable,
+{
+    if DEBUG_CHECK {
+        ok_and(value, cond, source)
+    } else {
+        value
+    }
+}
+
+/// Asserts like [`err_and`] only in debug builds.
+#[track_caller]
+#[inline]
+pub fn debug_err_and<T, E>(
+    value: Result<T, E>,
+    cond: impl FnOnce(&E) -> bool,
+    source: &'static str,
+) -> Result<T, E>
+where
+    T: Printable,
+    E: Printable,
+{
+    if DEBUG_CHECK {
+        err_and(value, cond, source)
+    } else {
+        value
+    }
+}
@@ -1,3 +1,8 @@
+use crate::invariant::Invariant;
+use crate::matcher::{Description, Matcher};
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+
 /// An extension trait to add the assertion_ok methods.
 pub trait AssertOkExt {
     /// Asserts the [`Result`] is [`Ok`].
@@ -33,7 +38,7 @@ pub trait AssertOkExt {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Err`]
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method returns self as is.
@@ -59,7 +64,7 @@ pub trait AssertOkAndExt<T> {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Err`], or [`Ok`] but user-provided condition returns `false`
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method return self as is.
@@ -81,7 +86,7 @@ pub trait AssertOkAndExt<T> {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Err`], or [`Ok`] but user-provided condition returns `false`
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method return self as is.
@@ -133,7 +138,7 @@ pub trait AssertErrExt {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Err`].
-    /// - `debug_assertions` is enabled.
+    /// - `debug_assertions` or `always-check` feature is enabled.
     /// - `passthrough` feature is disabled.
     ///
     /// Otherwise, the method returns self as is.
@@ -167,7 +172,7 @@ pub trait AssertErrAndExt<T, E> {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Ok`], or [`Err`] but user-provided condition returns `false`
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method return self as is.
@@ -196,7 +201,7 @@ pub trait AssertErrAndExt<T, E> {
     /// The method panics if all following conditions are satisfied:
     ///
     /// - It is [`Ok`], or [`Err`] but user-provided condition returns `false`
-    /// - `debug_assertions` is enabled
+    /// - `debug_assertions` or `always-check` feature is enabled
     /// - `passthrough` feature is disabled
     ///
     /// Otherwise, the method return self as is.
@@ -221,116 +226,1773 @@ pub trait AssertErrAndExt<T, E> {
     fn debug_assert_err_and(self, cond: impl FnOnce(&E) -> bool) -> Self;
 }
 
-impl<T, E> AssertOkExt for Result<T, E>
-where
-    E: crate::fmt::Debug,
-{
-    #[track_caller]
-    #[inline]
-    fn assert_ok(self) -> Self {
-        if let Err(ref x) = self {
-            panic!("Expected Ok(_), got Err({:?})", x);
-        }
-        self
-    }
+/// An extension trait to add the assertion_ok_in methods.
+pub trait AssertOkInExt<T> {
+    /// Asserts the [`Result`] is [`Ok`] and its value is in the range.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is out of the range, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.assert_ok_in(0..=100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(101);
+    /// let _ = x.assert_ok_in(0..=100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable;
 
-    #[track_caller]
-    #[inline]
-    fn debug_assert_ok(self) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
-        {
-            if let Err(ref x) = self {
-                panic!("Expected Ok(_), got Err({:?})", x);
-            }
-        }
-        self
-    }
+    /// Asserts the [`Result`] is [`Ok`] and its value is in the range only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is out of the range
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.debug_assert_ok_in(0..=100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_ok_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable;
 }
 
-impl<T, E> AssertOkAndExt<T> for Result<T, E>
-where
-    T: crate::fmt::Debug,
-    E: crate::fmt::Debug,
-{
-    #[track_caller]
-    #[inline]
-    fn assert_ok_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
-        match self {
-            Ok(ref x) if cond(x) => { /* do nothing */ }
-            Ok(ref x) => panic!("Condition not satisfied for Ok({:?})", x),
-            Err(ref x) => panic!("Expected Ok(_), got Err({:?})", x),
-        }
-        self
-    }
+/// An extension trait to add the assertion_ok_comparison methods.
+pub trait AssertOkCmpExt<T> {
+    /// Asserts the [`Result`] is [`Ok`] and its value is less than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is not less than the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.assert_ok_lt(&100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(100);
+    /// let _ = x.assert_ok_lt(&100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
 
-    #[track_caller]
-    #[inline]
-    fn debug_assert_ok_and(self, _cond: impl FnOnce(&T) -> bool) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
-        {
-            match self {
-                Ok(ref x) if _cond(x) => { /* do nothing */ }
-                Ok(ref x) => panic!("Condition not satisfied for Ok({:?})", x),
-                Err(ref x) => panic!("Expected Ok(_), got Err({:?})", x),
-            }
-        }
-        self
-    }
-}
+    /// Asserts the [`Result`] is [`Ok`] and its value is less than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is not less than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.debug_assert_ok_lt(&100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_ok_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
 
-impl<T, E> AssertErrExt for Result<T, E>
-where
-    T: crate::fmt::Debug,
-{
-    #[track_caller]
-    #[inline]
-    fn assert_err(self) -> Self {
-        if let Ok(ref x) = self {
-            panic!("Expected Err(_), got Ok({:?})", x);
-        }
-        self
-    }
+    /// Asserts the [`Result`] is [`Ok`] and its value is less than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is not less than or equal to the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(100);
+    /// let x = x.assert_ok_le(&100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(200));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(101);
+    /// let _ = x.assert_ok_le(&100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
 
-    #[track_caller]
-    #[inline]
-    fn debug_assert_err(self) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
-        {
-            if let Ok(ref x) = self {
-                panic!("Expected Err(_), got Ok({:?})", x);
-            }
-        }
-        self
-    }
-}
+    /// Asserts the [`Result`] is [`Ok`] and its value is less than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is not less than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(100);
+    /// let x = x.debug_assert_ok_le(&100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(200));
+    /// ```
+    fn debug_assert_ok_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
 
-impl<T, E> AssertErrAndExt<T, E> for Result<T, E>
-where
-    T: crate::fmt::Debug,
-    E: crate::fmt::Debug,
-{
-    #[track_caller]
-    #[inline]
-    fn assert_err_and(self, cond: impl FnOnce(&E) -> bool) -> Self {
-        match self {
-            Err(ref x) if cond(x) => { /* do nothing */ }
-            Err(ref x) => panic!("Condition not satisfied for Err({:?})", x),
-            Ok(ref x) => panic!("Expected Err(_), got Ok({:?})", x),
+    /// Asserts the [`Result`] is [`Ok`] and its value is greater than the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is not greater than the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.assert_ok_gt(&0).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(0);
+    /// let _ = x.assert_ok_gt(&0).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value is greater than the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is not greater than the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.debug_assert_ok_gt(&0).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_ok_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value is greater than or equal to the bound.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is not greater than or equal to the bound, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(0);
+    /// let x = x.assert_ok_ge(&0).map(|x| x * 2);
+    /// assert_eq!(x, Ok(0));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(-1);
+    /// let _ = x.assert_ok_ge(&0).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value is greater than or equal to the bound only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is not greater than or equal to the bound
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(0);
+    /// let x = x.debug_assert_ok_ge(&0).map(|x| x * 2);
+    /// assert_eq!(x, Ok(0));
+    /// ```
+    fn debug_assert_ok_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value is between `lo` and `hi`, both inclusive.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value is not between `lo` and `hi`, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.assert_ok_between(&0, &100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(101);
+    /// let _ = x.assert_ok_between(&0, &100).map(|x| x * 2);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value is between `lo` and `hi`, both inclusive only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value is not between `lo` and `hi`
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.debug_assert_ok_between(&0, &100).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_ok_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>;
+}
+
+/// An extension trait to add the assertion_ok_invariants methods.
+pub trait AssertOkInvariantsExt {
+    /// Asserts the [`Result`] is [`Ok`] and the invariants of its value hold.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or any invariant of the value is violated, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use chain_assertions::{Invariant, InvariantViolation};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Even(u32);
+    ///
+    /// impl Invariant for Even {
+    ///     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    ///         if self.0 % 2 != 0 {
+    ///             return Err(InvariantViolation::new("self.0 % 2 == 0"));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let x: Result<Even, &str> = Ok(Even(42));
+    /// let x = x.assert_ok_invariants().map(|x| x.0 / 2);
+    /// assert_eq!(x, Ok(21));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    /// # use chain_assertions::{Invariant, InvariantViolation};
+    /// #
+    /// # #[derive(Debug, PartialEq)]
+    /// # struct Even(u32);
+    /// #
+    /// # impl Invariant for Even {
+    /// #     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    /// #         if self.0 % 2 != 0 {
+    /// #             return Err(InvariantViolation::new("self.0 % 2 == 0"));
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let x: Result<Even, &str> = Ok(Even(21));
+    /// let _ = x.assert_ok_invariants();
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_invariants(self) -> Self;
+
+    /// Asserts the [`Result`] is [`Ok`] and the invariants of its value hold only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or any invariant of the value is violated
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use chain_assertions::{Invariant, InvariantViolation};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Even(u32);
+    ///
+    /// impl Invariant for Even {
+    ///     fn check_invariants(&self) -> Result<(), InvariantViolation> {
+    ///         if self.0 % 2 != 0 {
+    ///             return Err(InvariantViolation::new("self.0 % 2 == 0"));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let x: Result<Even, &str> = Ok(Even(42));
+    /// let x = x.debug_assert_ok_invariants().map(|x| x.0 / 2);
+    /// assert_eq!(x, Ok(21));
+    /// ```
+    fn debug_assert_ok_invariants(self) -> Self;
+}
+
+/// An extension trait to add the assertion_ok_matching methods.
+pub trait AssertOkMatchingExt<T> {
+    /// Asserts the [`Result`] is [`Ok`] and its value matches the matcher.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the value does not match, the method panics with the description
+    /// of the matcher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::{all_of, gt, lt};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(5);
+    /// let x = x.assert_ok_matching(all_of((gt(0), lt(10)))).map(|x| x * 2);
+    /// assert_eq!(x, Ok(10));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::matcher::{all_of, gt, lt};
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(12);
+    /// let _ = x.assert_ok_matching(all_of((gt(0), lt(10))));
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_matching<M: Matcher<T>>(self, matcher: M) -> Self;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value matches the matcher only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the value does not match
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::matcher::eq;
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = x.debug_assert_ok_matching(eq(21)).map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_assert_ok_matching<M: Matcher<T>>(self, matcher: M) -> Self;
+}
+
+/// An extension trait to add the assertion_ok_labeled methods.
+pub trait AssertOkLabeledExt {
+    /// Asserts the [`Result`] is [`Ok`], naming the step of the chain in the failure message.
+    ///
+    /// The label tells apart the steps sharing a line in a long chain.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`], the method panics with the label.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<&str, &str> = Ok("cipher");
+    /// let x = x.assert_ok_labeled("decrypt").map(str::len);
+    /// assert_eq!(x, Ok(6));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<&str, &str> = Err("bad key");
+    /// let _ = x.assert_ok_labeled("decrypt").map(str::len);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_labeled(self, label: &str) -> Self;
+
+    /// Asserts the [`Result`] is [`Ok`], naming the step of the chain, only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<&str, &str> = Ok("cipher");
+    /// let x = x.debug_assert_ok_labeled("decrypt").map(str::len);
+    /// assert_eq!(x, Ok(6));
+    /// ```
+    fn debug_assert_ok_labeled(self, label: &str) -> Self;
+}
+
+/// An extension trait to add the assertion_ok_eq_with methods.
+pub trait AssertOkEqWithExt<T> {
+    /// Asserts the [`Result`] is [`Ok`] and its value equals the one computed by
+    /// the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the values differ, the method panics with both of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "42".parse::<u8>().assert_ok_eq_with(|| u8::from_str_radix("42", 10).unwrap());
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<u8, &str> = Ok(41);
+    /// let _ = x.assert_ok_eq_with(|| 42);
+    /// //        ^-- panics here
+    /// ```
+    fn assert_ok_eq_with<U, F>(self, reference: F) -> Self
+    where
+        U: crate::Printable,
+        F: FnOnce() -> U,
+        T: PartialEq<U>;
+
+    /// Asserts the [`Result`] is [`Ok`] and its value equals the one computed by
+    /// the reference implementation only in debug builds.
+    ///
+    /// The reference implementation is called only when the value is checked.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the values differ
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// fn slow_parse(text: &str) -> u32 {
+    ///     text.bytes().fold(0, |n, b| n * 10 + u32::from(b - b'0'))
+    /// }
+    ///
+    /// let text = "1234";
+    /// let x = text.parse::<u32>().debug_assert_ok_eq_with(|| slow_parse(text));
+    /// assert_eq!(x, Ok(1234));
+    /// ```
+    fn debug_assert_ok_eq_with<U, F>(self, reference: F) -> Self
+    where
+        U: crate::Printable,
+        F: FnOnce() -> U,
+        T: PartialEq<U>;
+}
+
+/// An extension trait to add the debug_inspect_ok methods.
+pub trait DebugInspectOkExt<T> {
+    /// Calls the function with the value if it is [`Ok`] only in debug builds.
+    ///
+    /// The function is called if all following conditions are satisfied:
+    ///
+    /// - It is [`Ok`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "21"
+    ///     .parse::<i32>()
+    ///     .debug_inspect_ok(|x| println!("parsed {}", x))
+    ///     .map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    fn debug_inspect_ok<F: FnOnce(&T)>(self, f: F) -> Self;
+}
+
+/// An extension trait to add the debug_inspect_err methods.
+pub trait DebugInspectErrExt<E> {
+    /// Calls the function with the error if it is [`Err`] only in debug builds.
+    ///
+    /// The function is called if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "x"
+    ///     .parse::<i32>()
+    ///     .debug_inspect_err(|e| println!("failed to parse: {}", e))
+    ///     .unwrap_or(0);
+    /// assert_eq!(x, 0);
+    /// ```
+    fn debug_inspect_err<F: FnOnce(&E)>(self, f: F) -> Self;
+}
+
+/// An extension trait to add the assume_ok methods.
+pub trait AssumeOkExt {
+    /// Assumes the [`Result`] is [`Ok`] and lets the optimizer rely on it in release builds.
+    ///
+    /// The assumption is checked whenever `debug_assertions` or `always-check` feature is enabled.
+    /// Unlike `debug_assert_ok`, `passthrough` feature does not disable the check, since it may be enabled
+    /// by another crate in the dependency graph and silently turn the check into undefined behavior.
+    /// When the check is disabled, the failing branch becomes unreachable for the optimizer,
+    /// so it can elide the following checks like `unwrap` in the chain.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    ///
+    /// # Safety
+    ///
+    /// It must be [`Ok`]. Calling this method on [`Err`] is *undefined behavior*
+    /// when neither `debug_assertions` nor `always-check` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(21);
+    /// let x = unsafe { x.assume_ok() }.map(|x| x * 2);
+    /// assert_eq!(x, Ok(42));
+    /// ```
+    unsafe fn assume_ok(self) -> Self;
+}
+
+/// An extension trait to add the assume_err methods.
+pub trait AssumeErrExt {
+    /// Assumes the [`Result`] is [`Err`] and lets the optimizer rely on it in release builds.
+    ///
+    /// The assumption is checked whenever `debug_assertions` or `always-check` feature is enabled.
+    /// Unlike `debug_assert_err`, `passthrough` feature does not disable the check, since it may be enabled
+    /// by another crate in the dependency graph and silently turn the check into undefined behavior.
+    /// When the check is disabled, the failing branch becomes unreachable for the optimizer,
+    /// so it can elide the following checks like `unwrap` in the chain.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Ok`]
+    /// - `debug_assertions` or `always-check` feature is enabled
+    ///
+    /// # Safety
+    ///
+    /// It must be [`Err`]. Calling this method on [`Ok`] is *undefined behavior*
+    /// when neither `debug_assertions` nor `always-check` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<&str, i32> = Err(21);
+    /// let x = unsafe { x.assume_err() }.map_err(|x| x * 2);
+    /// assert_eq!(x, Err(42));
+    /// ```
+    unsafe fn assume_err(self) -> Self;
+}
+
+impl<T, E> AssertOkExt for &Result<T, E>
+where
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok(self) -> Self {
+        if let Err(ref x) = self {
+            fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x);
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Err(ref x) = self {
+                fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x);
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkAndExt<T> for &Result<T, E>
+where
+    T: crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+        match self {
+            Ok(ref x) if cond(x) => { /* do nothing */ }
+            Ok(ref x) => fail!(
+                ConditionNotSatisfied,
+                "Condition not satisfied for Ok({:?})",
+                x
+            ),
+            Err(ref x) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_and(self, _cond: impl FnOnce(&T) -> bool) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref x) if _cond(x) => { /* do nothing */ }
+                Ok(ref x) => fail!(
+                    ConditionNotSatisfied,
+                    "Condition not satisfied for Ok({:?})",
+                    x
+                ),
+                Err(ref x) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertErrExt for &Result<T, E>
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_err(self) -> Self {
+        if let Ok(ref x) = self {
+            fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x);
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_err(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Ok(ref x) = self {
+                fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x);
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertErrAndExt<T, E> for &Result<T, E>
+where
+    T: crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_err_and(self, cond: impl FnOnce(&E) -> bool) -> Self {
+        match self {
+            Err(ref x) if cond(x) => { /* do nothing */ }
+            Err(ref x) => fail!(
+                ConditionNotSatisfied,
+                "Condition not satisfied for Err({:?})",
+                x
+            ),
+            Ok(ref x) => fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_err_and(self, _cond: impl FnOnce(&E) -> bool) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Err(ref x) if _cond(x) => { /* do nothing */ }
+                Err(ref x) => fail!(
+                    ConditionNotSatisfied,
+                    "Condition not satisfied for Err({:?})",
+                    x
+                ),
+                Ok(ref x) => fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkInExt<T> for &Result<T, E>
+where
+    T: PartialOrd + crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_in<R>(self, range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable,
+    {
+        match self {
+            Ok(ref x) if range.contains(x) => { /* do nothing */ }
+            Ok(ref x) => fail!(OutOfRange, "Expected Ok(_) in {:?}, got Ok({:?})", range, x),
+            Err(ref x) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_in<R>(self, _range: R) -> Self
+    where
+        R: RangeBounds<T> + crate::Printable,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref x) if _range.contains(x) => { /* do nothing */ }
+                Ok(ref x) => fail!(
+                    OutOfRange,
+                    "Expected Ok(_) in {:?}, got Ok({:?})",
+                    _range,
+                    x
+                ),
+                Err(ref x) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkCmpExt<T> for &Result<T, E>
+where
+    T: crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_lt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Ok(ref v) if *v < *bound => { /* do nothing */ }
+            Ok(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Ok(_) less than {:?}, got Ok({:?})",
+                bound,
+                v
+            ),
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_lt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if *v < *_bound => { /* do nothing */ }
+                Ok(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Ok(_) less than {:?}, got Ok({:?})",
+                    _bound,
+                    v
+                ),
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_ok_le<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Ok(ref v) if *v <= *bound => { /* do nothing */ }
+            Ok(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Ok(_) less than or equal to {:?}, got Ok({:?})",
+                bound,
+                v
+            ),
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_le<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if *v <= *_bound => { /* do nothing */ }
+                Ok(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Ok(_) less than or equal to {:?}, got Ok({:?})",
+                    _bound,
+                    v
+                ),
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_ok_gt<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Ok(ref v) if *v > *bound => { /* do nothing */ }
+            Ok(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Ok(_) greater than {:?}, got Ok({:?})",
+                bound,
+                v
+            ),
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_gt<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if *v > *_bound => { /* do nothing */ }
+                Ok(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Ok(_) greater than {:?}, got Ok({:?})",
+                    _bound,
+                    v
+                ),
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_ok_ge<B>(self, bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Ok(ref v) if *v >= *bound => { /* do nothing */ }
+            Ok(ref v) => fail!(
+                ComparisonFailed,
+                "Expected Ok(_) greater than or equal to {:?}, got Ok({:?})",
+                bound,
+                v
+            ),
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_ge<B>(self, _bound: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if *v >= *_bound => { /* do nothing */ }
+                Ok(ref v) => fail!(
+                    ComparisonFailed,
+                    "Expected Ok(_) greater than or equal to {:?}, got Ok({:?})",
+                    _bound,
+                    v
+                ),
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_ok_between<B>(self, lo: &B, hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        match self {
+            Ok(ref v) if *v >= *lo && *v <= *hi => { /* do nothing */ }
+            Ok(ref v) => fail!(
+                OutOfRange,
+                "Expected Ok(_) between {:?} and {:?}, got Ok({:?})",
+                lo,
+                hi,
+                v
+            ),
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_between<B>(self, _lo: &B, _hi: &B) -> Self
+    where
+        B: ?Sized + crate::Printable,
+        T: PartialOrd<B>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if *v >= *_lo && *v <= *_hi => { /* do nothing */ }
+                Ok(ref v) => fail!(
+                    OutOfRange,
+                    "Expected Ok(_) between {:?} and {:?}, got Ok({:?})",
+                    _lo,
+                    _hi,
+                    v
+                ),
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkInvariantsExt for &Result<T, E>
+where
+    T: Invariant + crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_invariants(self) -> Self {
+        match self {
+            Ok(ref v) => {
+                if let Err(violation) = v.check_invariants() {
+                    let condition = violation.condition();
+                    fail!(
+                        InvariantViolated,
+                        "Invariant {:?} violated by Ok({:?})",
+                        condition,
+                        v
+                    );
+                }
+            }
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_invariants(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) => {
+                    if let Err(violation) = v.check_invariants() {
+                        let condition = violation.condition();
+                        fail!(
+                            InvariantViolated,
+                            "Invariant {:?} violated by Ok({:?})",
+                            condition,
+                            v
+                        );
+                    }
+                }
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkMatchingExt<T> for &Result<T, E>
+where
+    T: crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+        match self {
+            Ok(ref v) if matcher.matches(v) => { /* do nothing */ }
+            Ok(ref v) => {
+                let expected = Description::new(&matcher);
+                fail!(NotMatched, "Expected Ok({:?}), got Ok({:?})", expected, v)
+            }
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_matching<M: Matcher<T>>(self, _matcher: M) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) if _matcher.matches(v) => { /* do nothing */ }
+                Ok(ref v) => {
+                    let expected = Description::new(&_matcher);
+                    fail!(NotMatched, "Expected Ok({:?}), got Ok({:?})", expected, v)
+                }
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssertOkLabeledExt for &Result<T, E>
+where
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_labeled(self, label: &str) -> Self {
+        if let Err(ref x) = self {
+            fail!(
+                ExpectedOk,
+                "Expected Ok(_) from {:?}, got Err({:?})",
+                label,
+                x
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_labeled(self, _label: &str) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Err(ref x) = self {
+                let label = _label;
+                fail!(
+                    ExpectedOk,
+                    "Expected Ok(_) from {:?}, got Err({:?})",
+                    label,
+                    x
+                );
+            }
+        }
+        self
+    }
+}
+impl<T, E> AssertOkEqWithExt<T> for &Result<T, E>
+where
+    T: crate::Printable,
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_ok_eq_with<U, F>(self, reference: F) -> Self
+    where
+        U: crate::Printable,
+        F: FnOnce() -> U,
+        T: PartialEq<U>,
+    {
+        match self {
+            Ok(ref v) => {
+                let expected = reference();
+                if *v != expected {
+                    fail!(
+                        CrossCheckFailed,
+                        "Expected Ok({:?}) computed by the reference, got Ok({:?})",
+                        expected,
+                        v
+                    );
+                }
+            }
+            Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_ok_eq_with<U, F>(self, _reference: F) -> Self
+    where
+        U: crate::Printable,
+        F: FnOnce() -> U,
+        T: PartialEq<U>,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            match self {
+                Ok(ref v) => {
+                    let expected = _reference();
+                    if *v != expected {
+                        fail!(
+                            CrossCheckFailed,
+                            "Expected Ok({:?}) computed by the reference, got Ok({:?})",
+                            expected,
+                            v
+                        );
+                    }
+                }
+                Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+            }
+        }
+        self
+    }
+}
+
+/// Forwards the assertions for [`Result`] and the mutable references to it to the implementation
+/// for the shared reference, so the failure paths are not duplicated for each receiver.
+macro_rules! forward_result_assertions {
+    ($([$($gen:tt)*] $ty:ty;)+) => {
+        $(
+            impl<$($gen)*> AssertOkExt for $ty
+            where
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok(self) -> Self {
+                    AssertOkExt::assert_ok(Borrow::<Result<T, E>>::borrow(&self));
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok(self) -> Self {
+                    AssertOkExt::debug_assert_ok(Borrow::<Result<T, E>>::borrow(&self));
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkAndExt<T> for $ty
+            where
+                T: crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+                    AssertOkAndExt::assert_ok_and(Borrow::<Result<T, E>>::borrow(&self), cond);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_and(self, cond: impl FnOnce(&T) -> bool) -> Self {
+                    AssertOkAndExt::debug_assert_ok_and(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        cond,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertErrExt for $ty
+            where
+                T: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_err(self) -> Self {
+                    AssertErrExt::assert_err(Borrow::<Result<T, E>>::borrow(&self));
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_err(self) -> Self {
+                    AssertErrExt::debug_assert_err(Borrow::<Result<T, E>>::borrow(&self));
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertErrAndExt<T, E> for $ty
+            where
+                T: crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_err_and(self, cond: impl FnOnce(&E) -> bool) -> Self {
+                    AssertErrAndExt::assert_err_and(Borrow::<Result<T, E>>::borrow(&self), cond);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_err_and(self, cond: impl FnOnce(&E) -> bool) -> Self {
+                    AssertErrAndExt::debug_assert_err_and(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        cond,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkInExt<T> for $ty
+            where
+                T: PartialOrd + crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_in<R>(self, range: R) -> Self
+                where
+                    R: RangeBounds<T> + crate::Printable,
+                {
+                    AssertOkInExt::assert_ok_in(Borrow::<Result<T, E>>::borrow(&self), range);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_in<R>(self, range: R) -> Self
+                where
+                    R: RangeBounds<T> + crate::Printable,
+                {
+                    AssertOkInExt::debug_assert_ok_in(Borrow::<Result<T, E>>::borrow(&self), range);
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkCmpExt<T> for $ty
+            where
+                T: crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_lt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::assert_ok_lt(Borrow::<Result<T, E>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_lt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::debug_assert_ok_lt(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ok_le<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::assert_ok_le(Borrow::<Result<T, E>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_le<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::debug_assert_ok_le(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ok_gt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::assert_ok_gt(Borrow::<Result<T, E>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_gt<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::debug_assert_ok_gt(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ok_ge<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::assert_ok_ge(Borrow::<Result<T, E>>::borrow(&self), bound);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_ge<B>(self, bound: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::debug_assert_ok_ge(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        bound,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ok_between<B>(self, lo: &B, hi: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::assert_ok_between(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        lo,
+                        hi,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_between<B>(self, lo: &B, hi: &B) -> Self
+                where
+                    B: ?Sized + crate::Printable,
+                    T: PartialOrd<B>,
+                {
+                    AssertOkCmpExt::debug_assert_ok_between(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        lo,
+                        hi,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkInvariantsExt for $ty
+            where
+                T: Invariant + crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_invariants(self) -> Self {
+                    AssertOkInvariantsExt::assert_ok_invariants(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_invariants(self) -> Self {
+                    AssertOkInvariantsExt::debug_assert_ok_invariants(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkMatchingExt<T> for $ty
+            where
+                T: crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+                    AssertOkMatchingExt::assert_ok_matching(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        matcher,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_matching<M: Matcher<T>>(self, matcher: M) -> Self {
+                    AssertOkMatchingExt::debug_assert_ok_matching(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        matcher,
+                    );
+                    self
+                }
+            }
+
+            impl<$($gen)*> AssertOkLabeledExt for $ty
+            where
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_labeled(self, label: &str) -> Self {
+                    AssertOkLabeledExt::assert_ok_labeled(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        label,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_labeled(self, label: &str) -> Self {
+                    AssertOkLabeledExt::debug_assert_ok_labeled(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        label,
+                    );
+                    self
+                }
+            }
+            impl<$($gen)*> AssertOkEqWithExt<T> for $ty
+            where
+                T: crate::Printable,
+                E: crate::Printable,
+            {
+                #[track_caller]
+                #[inline]
+                fn assert_ok_eq_with<U, F>(self, reference: F) -> Self
+                where
+                    U: crate::Printable,
+                    F: FnOnce() -> U,
+                    T: PartialEq<U>,
+                {
+                    AssertOkEqWithExt::assert_ok_eq_with(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        reference,
+                    );
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ok_eq_with<U, F>(self, reference: F) -> Self
+                where
+                    U: crate::Printable,
+                    F: FnOnce() -> U,
+                    T: PartialEq<U>,
+                {
+                    AssertOkEqWithExt::debug_assert_ok_eq_with(
+                        Borrow::<Result<T, E>>::borrow(&self),
+                        reference,
+                    );
+                    self
+                }
+            }
+        )+
+    };
+}
+
+forward_result_assertions! {
+    [T, E] Result<T, E>;
+    ['a, T, E] &'a mut Result<T, E>;
+}
+
+impl<T, E> DebugInspectOkExt<T> for Result<T, E> {
+    #[inline]
+    fn debug_inspect_ok<F: FnOnce(&T)>(self, _f: F) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Ok(ref x) = self {
+                _f(x);
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> DebugInspectErrExt<E> for Result<T, E> {
+    #[inline]
+    fn debug_inspect_err<F: FnOnce(&E)>(self, _f: F) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            if let Err(ref x) = self {
+                _f(x);
+            }
+        }
+        self
+    }
+}
+
+impl<T, E> AssumeOkExt for Result<T, E>
+where
+    E: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    unsafe fn assume_ok(self) -> Self {
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        {
+            if let Err(ref x) = self {
+                fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", x);
+            }
+        }
+        if self.is_err() {
+            core::hint::unreachable_unchecked();
         }
         self
     }
+}
 
+impl<T, E> AssumeErrExt for Result<T, E>
+where
+    T: crate::Printable,
+{
     #[track_caller]
     #[inline]
-    fn debug_assert_err_and(self, _cond: impl FnOnce(&E) -> bool) -> Self {
-        #[cfg(all(debug_assertions, not(feature = "passthrough")))]
+    unsafe fn assume_err(self) -> Self {
+        #[cfg(any(debug_assertions, feature = "always-check"))]
         {
-            match self {
-                Err(ref x) if _cond(x) => { /* do nothing */ }
-                Err(ref x) => panic!("Condition not satisfied for Err({:?})", x),
-                Ok(ref x) => panic!("Expected Err(_), got Ok({:?})", x),
+            if let Ok(ref x) = self {
+                fail!(ExpectedErr, "Expected Err(_), got Ok({:?})", x);
             }
         }
+        if self.is_ok() {
+            core::hint::unreachable_unchecked();
+        }
         self
     }
 }
@@ -403,7 +2065,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Expected Ok(_), got Err(Debuggable)"
         )]
         fn it_fails_on_err() {
@@ -429,7 +2094,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Condition not satisfied for Ok(21)"
         )]
         fn it_fails_on_invalid_ok() {
@@ -443,9 +2111,13 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Expected Ok(_), got Err(Debuggable)"
         )]
+        #[allow(clippy::map_identity)]
         fn it_fails_on_err() {
             let x: Result<i32, Debuggable> = Err(Debuggable);
             let x = x.debug_assert_ok_and(|x| x >= &20).map(|x| x);
@@ -517,10 +2189,12 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Expected Err(_), got Ok(Debuggable)"
         )]
-
         fn it_fails_on_ok_only_in_debug_mode() {
             let x: Result<Debuggable, i32> = Ok(Debuggable);
             let x = x.debug_assert_err().map_err(|x| x + 1);
@@ -544,7 +2218,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Condition not satisfied for Err(21)"
         )]
         fn it_fails_on_invalid_err() {
@@ -557,7 +2234,10 @@ mod tests {
 
         #[test]
         #[cfg_attr(
-            all(debug_assertions, not(feature = "passthrough")),
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
             should_panic = "Expected Err(_), got Ok(Debuggable)"
         )]
         fn it_fails_on_ok() {
@@ -569,4 +2249,547 @@ mod tests {
             assert!(matches!(x, Ok(Debuggable)), "Expected Ok(Debuggable)");
         }
     }
+
+    mod assume_ok {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_ok() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = unsafe { x.assume_ok() }.map(|x| x * 2);
+
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        #[should_panic(expected = "Expected Ok(_), got Err(Debuggable)")]
+        fn it_fails_on_err_only_if_checked() {
+            let x: Result<NonDebuggable, Debuggable> = Err(Debuggable);
+            let _ = unsafe { x.assume_ok() };
+            //               ^-- should panic here only if checked
+        }
+    }
+
+    mod assume_err {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_on_err() {
+            let x: Result<Debuggable, i32> = Err(21);
+            let x = unsafe { x.assume_err() }.map_err(|x| x * 2);
+
+            assert_eq!(x, Err(42));
+        }
+
+        #[test]
+        #[cfg(any(debug_assertions, feature = "always-check"))]
+        #[should_panic(expected = "Expected Err(_), got Ok(Debuggable)")]
+        fn it_fails_on_ok_only_if_checked() {
+            let x: Result<Debuggable, NonDebuggable> = Ok(Debuggable);
+            let _ = unsafe { x.assume_err() };
+            //               ^-- should panic here only if checked
+        }
+    }
+
+    mod assert_ok_in {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = x.assert_ok_in(0..=100).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_) in 0..=100, got Ok(101)")]
+        fn it_fails_out_of_range() {
+            let x: Result<i32, Debuggable> = Ok(101);
+            let _ = x.assert_ok_in(0..=100).map(|x| x * 2);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(Debuggable)")]
+        fn it_fails_on_err() {
+            let x: Result<i32, Debuggable> = Err(Debuggable);
+            let _ = x.assert_ok_in(0..=100).map(|x| x * 2);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_in {
+        use super::{super::*, *};
+
+        #[test]
+        fn it_succeeds_in_range() {
+            let x: Result<i32, Debuggable> = Ok(21);
+            let x = x.debug_assert_ok_in(0..=100).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Ok(_) in 0..=100, got Ok(101)")
+        )]
+        fn it_fails_out_of_range() {
+            let x: Result<i32, Debuggable> = Ok(101);
+            let x = x.debug_assert_ok_in(0..=100);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok(101));
+        }
+    }
+
+    mod assert_ok_lt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_less() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.assert_ok_lt(&100).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_) less than 100, got Ok(100)")]
+        fn it_fails_on_equal() {
+            let x: Result<i32, &str> = Ok(100);
+            let _ = x.assert_ok_lt(&100);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"error\")")]
+        fn it_fails_on_err() {
+            let x: Result<i32, &str> = Err("error");
+            let _ = x.assert_ok_lt(&100);
+            //        ^-- should panic here
+        }
+    }
+
+    mod assert_ok_ge {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_equal() {
+            let x: Result<i32, &str> = Ok(0);
+            let x = x.assert_ok_ge(&0);
+            assert_eq!(x, Ok(0));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_) greater than or equal to 0, got Ok(-1)")]
+        fn it_fails_on_less() {
+            let x: Result<i32, &str> = Ok(-1);
+            let _ = x.assert_ok_ge(&0);
+            //        ^-- should panic here
+        }
+    }
+
+    mod assert_ok_between {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_between_bounds() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.assert_ok_between(&0, &100).map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_) between 0 and 100, got Ok(101)")]
+        fn it_fails_out_of_bounds() {
+            let x: Result<i32, &str> = Ok(101);
+            let _ = x.assert_ok_between(&0, &100);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_gt {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_greater() {
+            let x: Result<i32, &str> = Ok(1);
+            let x = x.debug_assert_ok_gt(&0);
+            assert_eq!(x, Ok(1));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Ok(_) greater than 0, got Ok(0)")
+        )]
+        fn it_fails_on_equal() {
+            let x: Result<i32, &str> = Ok(0);
+            let x = x.debug_assert_ok_gt(&0);
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok(0));
+        }
+    }
+
+    mod assert_ok_invariants {
+        use super::super::*;
+        use crate::invariant::tests::Player;
+
+        #[test]
+        fn it_succeeds_on_valid_ok() {
+            let x: Result<Player, &str> = Ok(Player {
+                hp: 80,
+                max_hp: 100,
+            });
+            let x = x.assert_ok_invariants().map(|x| x.hp);
+            assert_eq!(x, Ok(80));
+        }
+
+        #[test]
+        #[should_panic(expected = "Invariant \"self.hp <= self.max_hp\" violated by \
+                                   Ok(Player { hp: 120, max_hp: 100 })")]
+        fn it_fails_on_violation() {
+            let x: Result<Player, &str> = Ok(Player {
+                hp: 120,
+                max_hp: 100,
+            });
+            let _ = x.assert_ok_invariants();
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"error\")")]
+        fn it_fails_on_err() {
+            let x: Result<Player, &str> = Err("error");
+            let _ = x.assert_ok_invariants();
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_invariants {
+        use super::super::*;
+        use crate::invariant::tests::Player;
+
+        #[test]
+        fn it_succeeds_on_valid_ok() {
+            let x: Result<Player, &str> = Ok(Player { hp: 0, max_hp: 100 });
+            let x = x.debug_assert_ok_invariants();
+            assert_eq!(x, Ok(Player { hp: 0, max_hp: 100 }));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Invariant \"self.hp <= self.max_hp\" violated by \
+                                     Ok(Player { hp: 101, max_hp: 100 })")
+        )]
+        fn it_fails_on_violation() {
+            let x: Result<Player, &str> = Ok(Player {
+                hp: 101,
+                max_hp: 100,
+            });
+            let x = x.debug_assert_ok_invariants();
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(
+                x,
+                Ok(Player {
+                    hp: 101,
+                    max_hp: 100
+                })
+            );
+        }
+    }
+
+    mod assert_ok_matching {
+        use super::super::*;
+        use crate::matcher::{all_of, eq, gt, lt};
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x
+                .assert_ok_matching(all_of((gt(20), lt(22))))
+                .map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(all of (greater than 0, less than 10)), got Ok(12)")]
+        fn it_fails_on_mismatch() {
+            let x: Result<i32, &str> = Ok(12);
+            let _ = x.assert_ok_matching(all_of((gt(0), lt(10))));
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"error\")")]
+        fn it_fails_on_err() {
+            let x: Result<i32, &str> = Err("error");
+            let _ = x.assert_ok_matching(eq(21));
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_matching {
+        use super::super::*;
+        use crate::matcher::{eq, not};
+
+        #[test]
+        fn it_succeeds_on_matched_value() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_assert_ok_matching(eq(21));
+            assert_eq!(x, Ok(21));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Ok(not equal to 21), got Ok(21)")
+        )]
+        fn it_fails_on_mismatch() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_assert_ok_matching(not(eq(21)));
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok(21));
+        }
+    }
+
+    mod assert_ok_labeled {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_ok() {
+            let x: Result<&str, &str> = Ok("cipher");
+            let x = x.assert_ok_labeled("decrypt").map(str::len);
+            assert_eq!(x, Ok(6));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_) from \"decrypt\", got Err(\"bad key\")")]
+        fn it_fails_on_err() {
+            let x: Result<&str, &str> = Ok("cipher");
+            let _ = x
+                .map(|_| Err::<&str, &str>("bad key"))
+                .assert_ok_labeled("read")
+                .and_then(|x| x)
+                .assert_ok_labeled("decrypt");
+            //   ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_labeled {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_ok() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_assert_ok_labeled("parse");
+            assert_eq!(x, Ok(21));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Ok(_) from \"parse\", got Err(\"error\")")
+        )]
+        fn it_fails_on_err() {
+            let x: Result<i32, &str> = Err("error");
+            let x = x.debug_assert_ok_labeled("parse");
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Err("error"));
+        }
+    }
+
+    mod debug_inspect_ok {
+        use super::super::*;
+
+        #[test]
+        fn it_calls_function_only_in_debug_builds() {
+            let mut inspected = None;
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_inspect_ok(|x| inspected = Some(*x));
+            assert_eq!(x, Ok(21));
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(inspected, if checked { Some(21) } else { None });
+        }
+
+        #[test]
+        fn it_skips_err() {
+            let mut called = false;
+            let x: Result<i32, &str> = Err("error");
+            let x = x.debug_inspect_ok(|_| called = true);
+            assert_eq!(x, Err("error"));
+            assert!(!called, "Expected function not called");
+        }
+    }
+
+    mod debug_inspect_err {
+        use super::super::*;
+
+        #[test]
+        fn it_calls_function_only_in_debug_builds() {
+            let mut inspected = None;
+            let x: Result<i32, &str> = Err("error");
+            let x = x.debug_inspect_err(|e| inspected = Some(*e));
+            assert_eq!(x, Err("error"));
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(inspected, if checked { Some("error") } else { None });
+        }
+
+        #[test]
+        fn it_skips_ok() {
+            let mut called = false;
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_inspect_err(|_| called = true);
+            assert_eq!(x, Ok(21));
+            assert!(!called, "Expected function not called");
+        }
+    }
+
+    mod borrowed {
+        use super::super::*;
+
+        struct Config {
+            port: Result<u16, &'static str>,
+        }
+
+        #[test]
+        fn it_succeeds_on_shared_reference() {
+            let config = Config { port: Ok(8080) };
+            let x = (&config.port)
+                .assert_ok_between(&1024, &65535)
+                .map(|port| port + 1);
+            assert_eq!(x, Ok(8081));
+        }
+
+        #[test]
+        fn it_succeeds_on_mutable_reference() {
+            let mut config = Config { port: Err("unset") };
+            *(&mut config.port).assert_err_and(|e| *e == "unset") = Ok(80);
+            assert_eq!(config.port, Ok(80));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"unset\")")]
+        fn it_fails_on_shared_reference() {
+            let config = Config { port: Err("unset") };
+            let _ = (&config.port).assert_ok();
+            //                     ^-- should panic here
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Err(_), got Ok(80)")
+        )]
+        fn it_fails_on_mutable_reference() {
+            let mut config = Config { port: Ok(80) };
+            let x = (&mut config.port).debug_assert_err();
+            //                         ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, &mut Ok(80));
+        }
+    }
+
+    mod assert_ok_eq_with {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_same_value() {
+            let x = "42".parse::<u8>().assert_ok_eq_with(|| 42);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(42) computed by the reference, got Ok(41)")]
+        fn it_fails_on_different_value() {
+            let x: Result<u8, &str> = Ok(41);
+            let _ = x.assert_ok_eq_with(|| 42);
+            //        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"error\")")]
+        fn it_fails_on_err() {
+            let x: Result<u8, &str> = Err("error");
+            let _ = x.assert_ok_eq_with(|| 42);
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ok_eq_with {
+        use super::super::*;
+
+        #[test]
+        fn it_calls_reference_only_in_debug_builds() {
+            let mut called = false;
+            let x: Result<u8, &str> = Ok(42);
+            let x = x.debug_assert_ok_eq_with(|| {
+                called = true;
+                42
+            });
+            assert_eq!(x, Ok(42));
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(called, checked);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected Ok(\"42\") computed by the reference, got Ok(\"042\")"
+            )
+        )]
+        fn it_fails_on_different_value() {
+            let x: Result<&str, &str> = Ok("042");
+            let x = x.debug_assert_ok_eq_with(|| "42");
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok("042"));
+        }
+    }
 }
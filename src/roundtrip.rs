@@ -0,0 +1,358 @@
+//! Round-trip assertions for encode/decode pairs.
+//!
+//! With the `serde_json` feature, [`AssertSerdeRoundtripExt`] checks the value survives
+//! the serialization to JSON and back.
+
+/// An extension trait to add the assertion_roundtrip methods.
+pub trait AssertRoundtripExt: Sized {
+    /// Asserts the value is restored by decoding its encoding, i.e. `decode(&encode(&v)) == v`.
+    ///
+    /// # Panics
+    ///
+    /// If the decoded value differs, the method panics with the intermediate encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 0x1234u16.assert_roundtrip(|x| x.to_be_bytes(), |b| u16::from_be_bytes(*b));
+    /// assert_eq!(x, 0x1234);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = 0x1234u16.assert_roundtrip(|x| x.to_be_bytes(), |b| u16::from_le_bytes(*b));
+    /// //                ^-- panics here
+    /// ```
+    fn assert_roundtrip<C, E, D>(self, encode: E, decode: D) -> Self
+    where
+        C: crate::Printable,
+        E: FnOnce(&Self) -> C,
+        D: FnOnce(&C) -> Self;
+
+    /// Asserts the value is restored by decoding its encoding only in debug builds.
+    ///
+    /// The encoder and the decoder are called only when the value is checked.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The decoded value differs
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = 'é'.debug_assert_roundtrip(|c| u32::from(*c), |n| char::from_u32(*n).unwrap());
+    /// assert_eq!(x, 'é');
+    /// ```
+    fn debug_assert_roundtrip<C, E, D>(self, encode: E, decode: D) -> Self
+    where
+        C: crate::Printable,
+        E: FnOnce(&Self) -> C,
+        D: FnOnce(&C) -> Self;
+}
+
+#[track_caller]
+#[inline]
+fn check_roundtrip<T, C, E, D>(value: &T, encode: E, decode: D)
+where
+    T: PartialEq + crate::Printable,
+    C: crate::Printable,
+    E: FnOnce(&T) -> C,
+    D: FnOnce(&C) -> T,
+{
+    let encoded = encode(value);
+    let decoded = decode(&encoded);
+    if decoded != *value {
+        fail!(
+            RoundtripFailed,
+            "Expected {:?} after the round trip, got {:?} decoded from {:?}",
+            value,
+            decoded,
+            encoded
+        );
+    }
+}
+
+impl<T> AssertRoundtripExt for T
+where
+    T: PartialEq + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_roundtrip<C, E, D>(self, encode: E, decode: D) -> Self
+    where
+        C: crate::Printable,
+        E: FnOnce(&Self) -> C,
+        D: FnOnce(&C) -> Self,
+    {
+        check_roundtrip(&self, encode, decode);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_roundtrip<C, E, D>(self, _encode: E, _decode: D) -> Self
+    where
+        C: crate::Printable,
+        E: FnOnce(&Self) -> C,
+        D: FnOnce(&C) -> Self,
+    {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_roundtrip(&self, _encode, _decode);
+        }
+        self
+    }
+}
+
+/// An extension trait to add the assertion_serde_roundtrip methods.
+#[cfg(feature = "serde_json")]
+pub trait AssertSerdeRoundtripExt: Sized {
+    /// Asserts the value is restored by serializing it to JSON and deserializing it back.
+    ///
+    /// # Panics
+    ///
+    /// If the serialization or the deserialization fails, or the deserialized value differs,
+    /// the method panics with the intermediate JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = vec![(1, "one".to_string())].assert_serde_roundtrip();
+    /// assert_eq!(x, [(1, "one".to_string())]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = Some(None::<i32>).assert_serde_roundtrip();
+    /// //                        ^-- panics here
+    /// ```
+    fn assert_serde_roundtrip(self) -> Self;
+
+    /// Asserts the value is restored by serializing it to JSON and deserializing it back
+    /// only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - The serialization or the deserialization fails, or the deserialized value differs
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = Some(42).debug_assert_serde_roundtrip().map(|x| x / 2);
+    /// assert_eq!(x, Some(21));
+    /// ```
+    fn debug_assert_serde_roundtrip(self) -> Self;
+}
+
+#[cfg(feature = "serde_json")]
+#[track_caller]
+#[inline]
+fn check_serde_roundtrip<T>(value: &T)
+where
+    T: PartialEq + serde::Serialize + serde::de::DeserializeOwned + crate::Printable,
+{
+    let json = match serde_json::to_string(value) {
+        Ok(json) => json,
+        Err(e) => {
+            let error = e.to_string();
+            let error = error.as_str();
+            fail!(
+                RoundtripFailed,
+                "Expected a serializable value, got {:?}: {:?}",
+                value,
+                error
+            );
+        }
+    };
+    let json = json.as_str();
+    match serde_json::from_str::<T>(json) {
+        Ok(ref decoded) if decoded == value => { /* do nothing */ }
+        Ok(ref decoded) => fail!(
+            RoundtripFailed,
+            "Expected {:?} after the round trip, got {:?} decoded from {:?}",
+            value,
+            decoded,
+            json
+        ),
+        Err(e) => {
+            let error = e.to_string();
+            let error = error.as_str();
+            fail!(
+                RoundtripFailed,
+                "Expected {:?} after the round trip, got an error {:?} decoding {:?}",
+                value,
+                error,
+                json
+            );
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> AssertSerdeRoundtripExt for T
+where
+    T: PartialEq + serde::Serialize + serde::de::DeserializeOwned + crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn assert_serde_roundtrip(self) -> Self {
+        check_serde_roundtrip(&self);
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_assert_serde_roundtrip(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            check_serde_roundtrip(&self);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_roundtrip {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_restored_value() {
+            let x = 0x1234u16.assert_roundtrip(|x| x.to_be_bytes(), |b| u16::from_be_bytes(*b));
+            assert_eq!(x, 0x1234);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected 4660 after the round trip, got 13330 decoded from [18, 52]"
+        )]
+        fn it_fails_on_different_value() {
+            let _ = 0x1234u16.assert_roundtrip(|x| x.to_be_bytes(), |b| u16::from_le_bytes(*b));
+            //                ^-- should panic here
+        }
+    }
+
+    mod debug_assert_roundtrip {
+        use super::super::*;
+
+        #[test]
+        fn it_calls_encoder_only_in_debug_builds() {
+            let mut called = false;
+            let x = 42u8.debug_assert_roundtrip(
+                |x| {
+                    called = true;
+                    *x
+                },
+                |x| *x,
+            );
+            assert_eq!(x, 42);
+
+            let checked = cfg!(all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ));
+            assert_eq!(called, checked);
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected -1 after the round trip, got 255 decoded from 255")
+        )]
+        fn it_fails_on_different_value() {
+            let x = (-1i16).debug_assert_roundtrip(|x| *x as u8, |b| i16::from(*b));
+            //              ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, -1);
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod assert_serde_roundtrip {
+        use super::super::*;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn it_succeeds_on_restored_value() {
+            let mut map = BTreeMap::new();
+            map.insert("level".to_string(), vec![Some(3), None]);
+            let x = map.clone().assert_serde_roundtrip();
+            assert_eq!(x, map);
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected Some(None) after the round trip, got None decoded from \"null\""
+        )]
+        fn it_fails_on_different_value() {
+            let _ = Some(None::<i32>).assert_serde_roundtrip();
+            //                        ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected NaN after the round trip, got an error")]
+        fn it_fails_on_decoding_error() {
+            let _ = f64::NAN.assert_serde_roundtrip();
+            //               ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    mod debug_assert_serde_roundtrip {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_restored_value() {
+            let x = Some(42).debug_assert_serde_roundtrip();
+            assert_eq!(x, Some(42));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected Some(None) after the round trip")
+        )]
+        fn it_fails_on_different_value() {
+            let x = Some(None::<i32>).debug_assert_serde_roundtrip();
+            //                        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Some(None));
+        }
+    }
+}
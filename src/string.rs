@@ -0,0 +1,851 @@
+//! Assertions for strings.
+//!
+//! On failure, the string is printed escaped, and truncated if it is long.
+
+use crate::preview::StrPreview;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// An extension trait to add the string assertion methods.
+pub trait AssertStrExt {
+    /// Asserts the string starts with the prefix.
+    ///
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string does not start with the prefix, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "v1.2.3".assert_starts_with("v").len();
+    /// assert_eq!(x, 6);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "1.2.3".assert_starts_with("v");
+    /// //              ^-- panics here
+    /// ```
+    fn assert_starts_with(self, prefix: &str) -> Self;
+
+    /// Asserts the string starts with the prefix only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string does not start with the prefix
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<String, ()> = Ok("v1.2.3".to_string());
+    /// let x = x.debug_assert_starts_with("v").map(|x| x.len());
+    /// assert_eq!(x, Ok(6));
+    /// ```
+    fn debug_assert_starts_with(self, prefix: &str) -> Self;
+
+    /// Asserts the string ends with the suffix.
+    ///
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string does not end with the suffix, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "image.png".assert_ends_with(".png").len();
+    /// assert_eq!(x, 9);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "image.jpg".assert_ends_with(".png");
+    /// //                  ^-- panics here
+    /// ```
+    fn assert_ends_with(self, suffix: &str) -> Self;
+
+    /// Asserts the string ends with the suffix only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string does not end with the suffix
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<String, ()> = Ok("image.png".to_string());
+    /// let x = x.debug_assert_ends_with(".png").map(|x| x.len());
+    /// assert_eq!(x, Ok(9));
+    /// ```
+    fn debug_assert_ends_with(self, suffix: &str) -> Self;
+
+    /// Asserts the string contains the pattern.
+    ///
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string does not contain the pattern, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "key=value".assert_contains("=").len();
+    /// assert_eq!(x, 9);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "key:value".assert_contains("=");
+    /// //                  ^-- panics here
+    /// ```
+    fn assert_contains(self, pattern: &str) -> Self;
+
+    /// Asserts the string contains the pattern only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string does not contain the pattern
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<String, ()> = Ok("key=value".to_string());
+    /// let x = x.debug_assert_contains("=").map(|x| x.len());
+    /// assert_eq!(x, Ok(9));
+    /// ```
+    fn debug_assert_contains(self, pattern: &str) -> Self;
+
+    /// Asserts the string consists of ASCII characters only.
+    ///
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string contains a non-ASCII character, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "hello".assert_ascii().len();
+    /// assert_eq!(x, 5);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = "héllo".assert_ascii();
+    /// //              ^-- panics here
+    /// ```
+    fn assert_ascii(self) -> Self;
+
+    /// Asserts the string consists of ASCII characters only only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string contains a non-ASCII character
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<String, ()> = Ok("hello".to_string());
+    /// let x = x.debug_assert_ascii().map(|x| x.len());
+    /// assert_eq!(x, Ok(5));
+    /// ```
+    fn debug_assert_ascii(self) -> Self;
+
+    /// Asserts the string has no leading or trailing whitespace.
+    ///
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string has leading or trailing whitespace, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "hello".assert_trimmed().len();
+    /// assert_eq!(x, 5);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let _ = " hello\n".assert_trimmed();
+    /// //                 ^-- panics here
+    /// ```
+    fn assert_trimmed(self) -> Self;
+
+    /// Asserts the string has no leading or trailing whitespace only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string has leading or trailing whitespace
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x: Result<String, ()> = Ok("hello".to_string());
+    /// let x = x.debug_assert_trimmed().map(|x| x.len());
+    /// assert_eq!(x, Ok(5));
+    /// ```
+    fn debug_assert_trimmed(self) -> Self;
+}
+
+/// An extension trait to add the assertion_matches_regex methods.
+#[cfg(feature = "regex")]
+pub trait AssertMatchesRegexExt {
+    /// Asserts the string matches the regular expression.
+    ///
+    /// The regex is compiled by the caller, so it can be reused across calls in hot paths.
+    /// For [`Result`], it also asserts the [`Result`] is [`Ok`].
+    ///
+    /// # Panics
+    ///
+    /// If it is [`Err`] or the string does not match the regex, the method panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use regex::Regex;
+    ///
+    /// let date = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    /// let x = "2024-01-31".assert_matches_regex(&date).len();
+    /// assert_eq!(x, 10);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use chain_assertions::prelude::*;
+    /// use regex::Regex;
+    ///
+    /// let date = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    /// let _ = "2024/01/31".assert_matches_regex(&date);
+    /// //                   ^-- panics here
+    /// ```
+    fn assert_matches_regex(self, regex: &regex::Regex) -> Self;
+
+    /// Asserts the string matches the regular expression only in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if all following conditions are satisfied:
+    ///
+    /// - It is [`Err`] or the string does not match the regex
+    /// - `debug_assertions` or `always-check` feature is enabled
+    /// - `passthrough` feature is disabled
+    ///
+    /// Otherwise, the method returns self as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    /// use regex::Regex;
+    ///
+    /// let date = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    /// let x = "2024-01-31".debug_assert_matches_regex(&date).len();
+    /// assert_eq!(x, 10);
+    /// ```
+    fn debug_assert_matches_regex(self, regex: &regex::Regex) -> Self;
+}
+
+#[track_caller]
+#[inline]
+fn check_starts_with(s: &str, prefix: &str) {
+    if !s.starts_with(prefix) {
+        let (prefix, actual) = (StrPreview(prefix), StrPreview(s));
+        fail!(
+            UnexpectedString,
+            "Expected a string starting with {:?}, got {:?}",
+            prefix,
+            actual
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_ends_with(s: &str, suffix: &str) {
+    if !s.ends_with(suffix) {
+        let (suffix, actual) = (StrPreview(suffix), StrPreview(s));
+        fail!(
+            UnexpectedString,
+            "Expected a string ending with {:?}, got {:?}",
+            suffix,
+            actual
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_contains(s: &str, pattern: &str) {
+    if !s.contains(pattern) {
+        let (pattern, actual) = (StrPreview(pattern), StrPreview(s));
+        fail!(
+            UnexpectedString,
+            "Expected a string containing {:?}, got {:?}",
+            pattern,
+            actual
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_ascii(s: &str) {
+    if let Some((index, c)) = s.char_indices().find(|(_, c)| !c.is_ascii()) {
+        let actual = StrPreview(s);
+        fail!(
+            NotAscii,
+            "Expected an ASCII string, got {:?} at index {:?}: {:?}",
+            c,
+            index,
+            actual
+        );
+    }
+}
+
+#[track_caller]
+#[inline]
+fn check_trimmed(s: &str) {
+    if s.trim() != s {
+        let actual = StrPreview(s);
+        fail!(
+            UnexpectedString,
+            "Expected a string without leading or trailing whitespace, got {:?}",
+            actual
+        );
+    }
+}
+
+#[cfg(feature = "regex")]
+#[track_caller]
+#[inline]
+fn check_matches_regex(s: &str, regex: &regex::Regex) {
+    if !regex.is_match(s) {
+        let (pattern, actual) = (regex.as_str(), StrPreview(s));
+        fail!(
+            UnexpectedString,
+            "Expected a string matching {:?}, got {:?}",
+            pattern,
+            actual
+        );
+    }
+}
+
+/// Returns the string in [`Ok`], or fails on [`Err`].
+#[track_caller]
+#[inline]
+fn ok_str<S, E>(result: &Result<S, E>) -> &str
+where
+    S: AsRef<str>,
+    E: crate::Printable,
+{
+    match result {
+        Ok(s) => s.as_ref(),
+        Err(ref e) => fail!(ExpectedOk, "Expected Ok(_), got Err({:?})", e),
+    }
+}
+
+macro_rules! impl_str_assertions {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bound:tt)*])? => |$this:ident| $s:expr;)+) => {
+        $(
+            impl<$($gen)*> AssertStrExt for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_starts_with(self, prefix: &str) -> Self {
+                    check_starts_with({ let $this = &self; $s }, prefix);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_starts_with(self, _prefix: &str) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_starts_with({ let $this = &self; $s }, _prefix);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ends_with(self, suffix: &str) -> Self {
+                    check_ends_with({ let $this = &self; $s }, suffix);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ends_with(self, _suffix: &str) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_ends_with({ let $this = &self; $s }, _suffix);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_contains(self, pattern: &str) -> Self {
+                    check_contains({ let $this = &self; $s }, pattern);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_contains(self, _pattern: &str) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_contains({ let $this = &self; $s }, _pattern);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_ascii(self) -> Self {
+                    check_ascii({ let $this = &self; $s });
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_ascii(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_ascii({ let $this = &self; $s });
+                    }
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn assert_trimmed(self) -> Self {
+                    check_trimmed({ let $this = &self; $s });
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_trimmed(self) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_trimmed({ let $this = &self; $s });
+                    }
+                    self
+                }
+            }
+
+            #[cfg(feature = "regex")]
+            impl<$($gen)*> AssertMatchesRegexExt for $ty $(where $($bound)*)? {
+                #[track_caller]
+                #[inline]
+                fn assert_matches_regex(self, regex: &regex::Regex) -> Self {
+                    check_matches_regex({ let $this = &self; $s }, regex);
+                    self
+                }
+
+                #[track_caller]
+                #[inline]
+                fn debug_assert_matches_regex(self, _regex: &regex::Regex) -> Self {
+                    #[cfg(all(
+                        any(debug_assertions, feature = "always-check"),
+                        not(feature = "passthrough")
+                    ))]
+                    {
+                        check_matches_regex({ let $this = &self; $s }, _regex);
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_str_assertions! {
+    ['a] &'a str => |s| s;
+    [S, E] Result<S, E> where [S: AsRef<str>, E: crate::Printable] => |r| ok_str(r);
+}
+
+#[cfg(feature = "alloc")]
+impl_str_assertions! {
+    [] String => |s| s;
+    ['a] &'a String => |s| s;
+}
+
+#[cfg(test)]
+mod tests {
+    mod assert_starts_with {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_prefix() {
+            let x = "v1.2.3".assert_starts_with("v").len();
+            assert_eq!(x, 6);
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a string starting with \"v\", got \"1.2.3\"")]
+        fn it_fails_without_prefix() {
+            let _ = "1.2.3".assert_starts_with("v");
+            //              ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected Ok(_), got Err(\"error\")")]
+        fn it_fails_on_err() {
+            let x: Result<&str, &str> = Err("error");
+            let _ = x.assert_starts_with("v");
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_starts_with {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_prefix() {
+            let x: Result<&str, ()> = Ok("v1.2.3");
+            let x = x.debug_assert_starts_with("v1.");
+            assert_eq!(x, Ok("v1.2.3"));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a string starting with \"v2.\", got \"v1.2.3\"")
+        )]
+        fn it_fails_without_prefix() {
+            let x: Result<&str, ()> = Ok("v1.2.3");
+            let x = x.debug_assert_starts_with("v2.");
+            //        ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, Ok("v1.2.3"));
+        }
+    }
+
+    mod assert_ends_with {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_suffix() {
+            let x = "image.png".assert_ends_with(".png");
+            assert_eq!(x, "image.png");
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a string ending with \".png\", got \"image.jpg\"")]
+        fn it_fails_without_suffix() {
+            let _ = "image.jpg".assert_ends_with(".png");
+            //                  ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ends_with {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_suffix() {
+            let x = "image.png".debug_assert_ends_with("png");
+            assert_eq!(x, "image.png");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a string ending with \"/\", got \"/usr/bin\"")
+        )]
+        fn it_fails_without_suffix() {
+            let x = "/usr/bin".debug_assert_ends_with("/");
+            //                 ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "/usr/bin");
+        }
+    }
+
+    mod assert_contains {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_pattern() {
+            let x = "key=value".assert_contains("=");
+            assert_eq!(x, "key=value");
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a string containing \"=\", got \"line\\nbreak\"")]
+        fn it_fails_without_pattern() {
+            let _ = "line\nbreak".assert_contains("=");
+            //                    ^-- should panic here
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected a string containing \"=\", got \"\
+                                   aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"..")]
+        fn it_fails_with_truncated_string() {
+            let x = core::str::from_utf8(&[b'a'; 100]).unwrap();
+            let _ = x.assert_contains("=");
+            //        ^-- should panic here
+        }
+    }
+
+    mod debug_assert_contains {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_pattern() {
+            let x = "key=value".debug_assert_contains("y=v");
+            assert_eq!(x, "key=value");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected a string containing \"=\", got \"key:value\"")
+        )]
+        fn it_fails_without_pattern() {
+            let x = "key:value".debug_assert_contains("=");
+            //                  ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "key:value");
+        }
+    }
+
+    mod assert_ascii {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_ascii() {
+            let x = "hello".assert_ascii();
+            assert_eq!(x, "hello");
+        }
+
+        #[test]
+        #[should_panic(expected = "Expected an ASCII string, got 'é' at index 1: \"héllo\"")]
+        fn it_fails_on_non_ascii() {
+            let _ = "héllo".assert_ascii();
+            //              ^-- should panic here
+        }
+    }
+
+    mod debug_assert_ascii {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_ascii() {
+            let x: Result<&str, ()> = Ok("hello");
+            let x = x.debug_assert_ascii();
+            assert_eq!(x, Ok("hello"));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = "Expected an ASCII string, got 'ß' at index 4: \"Straße\"")
+        )]
+        fn it_fails_on_non_ascii() {
+            let x = "Straße".debug_assert_ascii();
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "Straße");
+        }
+    }
+
+    mod assert_trimmed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_trimmed() {
+            let x = "hello world".assert_trimmed();
+            assert_eq!(x, "hello world");
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Expected a string without leading or trailing whitespace, got \"hello\\n\""
+        )]
+        fn it_fails_on_trailing_newline() {
+            let _ = "hello\n".assert_trimmed();
+            //                ^-- should panic here
+        }
+    }
+
+    mod debug_assert_trimmed {
+        use super::super::*;
+
+        #[test]
+        fn it_succeeds_on_trimmed() {
+            let x = "hello".debug_assert_trimmed();
+            assert_eq!(x, "hello");
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(
+                expected = "Expected a string without leading or trailing whitespace, got \" hello\""
+            )
+        )]
+        fn it_fails_on_leading_space() {
+            let x = " hello".debug_assert_trimmed();
+            //               ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, " hello");
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod assert_matches_regex {
+        use super::super::*;
+        use regex::Regex;
+
+        #[test]
+        fn it_succeeds_on_match() {
+            let date = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+            let x = "2024-01-31".assert_matches_regex(&date);
+            assert_eq!(x, "2024-01-31");
+        }
+
+        #[test]
+        fn it_reuses_compiled_regex() {
+            let digits = Regex::new(r"^\d+$").unwrap();
+            let x = "1".assert_matches_regex(&digits);
+            let y = "23".assert_matches_regex(&digits);
+            assert_eq!((x, y), ("1", "23"));
+        }
+
+        #[test]
+        #[should_panic(expected = r#"Expected a string matching "^\\d+$", got "12a""#)]
+        fn it_fails_on_mismatch() {
+            let digits = Regex::new(r"^\d+$").unwrap();
+            let _ = "12a".assert_matches_regex(&digits);
+            //            ^-- should panic here
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod debug_assert_matches_regex {
+        use super::super::*;
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn it_succeeds_on_match() {
+            let lower = regex::Regex::new("^[a-z]+$").unwrap();
+            let x: Result<String, ()> = Ok("abc".into());
+            let x = x.debug_assert_matches_regex(&lower);
+            assert_eq!(x, Ok("abc".into()));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                any(debug_assertions, feature = "always-check"),
+                not(feature = "passthrough")
+            ),
+            should_panic(expected = r#"Expected a string matching "^[a-z]+$", got "ABC""#)
+        )]
+        fn it_fails_on_mismatch() {
+            let lower = regex::Regex::new("^[a-z]+$").unwrap();
+            let x = "ABC".debug_assert_matches_regex(&lower);
+            //            ^-- should panic here only in debug builds
+
+            // for release builds
+            assert_eq!(x, "ABC");
+        }
+    }
+}
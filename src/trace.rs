@@ -0,0 +1,157 @@
+//! Debug-only tracing steps for method chains.
+//!
+//! The methods print the value only when the `debug_assert_*` methods check it,
+//! i.e. `debug_assertions` or `always-check` feature is enabled and `passthrough` feature is disabled.
+//! The values are printed to the standard error with the `std` feature,
+//! or logged through `defmt::debug!` with the `defmt` feature.
+//! Otherwise, the methods print nothing.
+
+#[cfg(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+))]
+use core::panic::Location;
+
+/// An extension trait to add the debug_trace methods.
+pub trait DebugTraceExt: Sized {
+    /// Prints the value with the label of the step only in debug builds,
+    /// e.g. `[src/main.rs:4:10] parse: Ok(42)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = "42".parse::<i32>().debug_trace("parse").map(|x| x * 2);
+    /// assert_eq!(x, Ok(84));
+    /// ```
+    fn debug_trace(self, label: &str) -> Self;
+
+    /// Pretty-prints the value like `dbg!` only in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chain_assertions::prelude::*;
+    ///
+    /// let x = Some(21).debug_dump().map(|x| x * 2);
+    /// assert_eq!(x, Some(42));
+    /// ```
+    fn debug_dump(self) -> Self;
+}
+
+/// Prints the value, with the label if given, and the location of the caller.
+#[cfg(all(
+    any(debug_assertions, feature = "always-check"),
+    not(feature = "passthrough")
+))]
+#[track_caller]
+#[inline]
+fn trace<T>(label: Option<&str>, value: &T)
+where
+    T: ?Sized + crate::Printable,
+{
+    let location = Location::caller();
+
+    #[cfg(feature = "defmt")]
+    match label {
+        Some(label) => defmt::debug!(
+            "[{=str}:{=u32}] {=str}: {}",
+            location.file(),
+            location.line(),
+            label,
+            defmt::Debug2Format(value)
+        ),
+        None => defmt::debug!(
+            "[{=str}:{=u32}] {}",
+            location.file(),
+            location.line(),
+            defmt::Debug2Format(value)
+        ),
+    }
+
+    #[cfg(all(feature = "std", not(feature = "defmt")))]
+    match label {
+        Some(label) => eprintln!(
+            "[{}:{}:{}] {}: {:?}",
+            location.file(),
+            location.line(),
+            location.column(),
+            label,
+            value
+        ),
+        None => eprintln!(
+            "[{}:{}:{}] {:#?}",
+            location.file(),
+            location.line(),
+            location.column(),
+            value
+        ),
+    }
+
+    #[cfg(not(any(feature = "std", feature = "defmt")))]
+    let _ = (location, label, value);
+}
+
+impl<T> DebugTraceExt for T
+where
+    T: crate::Printable,
+{
+    #[track_caller]
+    #[inline]
+    fn debug_trace(self, _label: &str) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            trace(Some(_label), &self);
+        }
+        self
+    }
+
+    #[track_caller]
+    #[inline]
+    fn debug_dump(self) -> Self {
+        #[cfg(all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ))]
+        {
+            trace(None, &self);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod debug_trace {
+        use super::super::*;
+
+        #[test]
+        fn it_returns_self() {
+            let x: Result<i32, &str> = Ok(21);
+            let x = x.debug_trace("parse").map(|x| x * 2);
+            assert_eq!(x, Ok(42));
+        }
+
+        #[test]
+        fn it_works_on_reference() {
+            let x = [1, 2, 3];
+            let x = (&x).debug_trace("load").iter().sum::<i32>();
+            assert_eq!(x, 6);
+        }
+    }
+
+    mod debug_dump {
+        use super::super::*;
+
+        #[test]
+        fn it_returns_self() {
+            let x: Option<&str> = None;
+            let x = x.debug_dump();
+            assert_eq!(x, None);
+        }
+    }
+}
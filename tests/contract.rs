@@ -0,0 +1,293 @@
+#![cfg(feature = "derive")]
+
+use chain_assertions::contract;
+
+#[contract(requires(divisor != 0), ensures(|ret| *ret <= dividend))]
+fn divide(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+#[contract(requires(!name.is_empty(), name.len() <= 8))]
+fn greet(name: &str) -> usize {
+    name.len()
+}
+
+#[contract(ensures(|ret: &Result<u32, String>| ret.is_ok()))]
+fn parse(text: &str) -> Result<u32, String> {
+    let value = text.parse::<u32>().map_err(|e| e.to_string())?;
+    if value == 0 {
+        return Ok(1);
+    }
+    Ok(value)
+}
+
+#[contract(ensures(|ret| *ret < 10))]
+fn wrongly_ensured(x: u32) -> u32 {
+    x * 2
+}
+
+#[contract(requires(x > 0))]
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+#[contract(ensures(|ret: &Option<u32>| ret.map_or(true, |x| x > 0)))]
+fn first_positive(items: &[u32]) -> Option<u32> {
+    let first = *items.first()?;
+    if first == 0 {
+        return ::core::option::Option::None;
+    }
+    Some(first)
+}
+
+#[contract(ensures(|ret: &_| ret.size_hint().0 == n))]
+fn evens(n: usize) -> impl Iterator<Item = usize> {
+    (0..n).map(|x| x * 2)
+}
+
+/// # Safety
+///
+/// The pointer must be valid for reads.
+#[contract(requires(!ptr.is_null()), ensures(|ret| *ret > 0))]
+unsafe fn read_positive(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+#[contract(requires(divisor != 0))]
+const fn const_divide(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+const HALF: u32 = const_divide(42, 2);
+
+#[contract(ensures(|ret| *ret <= 10))]
+fn clamp_to_ten(x: u32) -> u32 {
+    if x > 10 {
+        return 10;
+    }
+    return x;
+}
+
+#[contract(ensures(|ret: &Vec<u32>| !ret.is_empty()))]
+fn singleton(x: u32) -> Vec<u32> {
+    vec![x]
+}
+
+#[contract(ensures(|_| true))]
+fn log_nothing(flag: bool) {
+    if flag {
+        return;
+    }
+    let _ = flag;
+}
+
+struct Counter {
+    count: u32,
+}
+
+struct Buffer {
+    items: Vec<u32>,
+}
+
+impl Buffer {
+    #[contract(ensures(|ret| ret.capacity() >= ret.len()))]
+    fn items_mut(&mut self) -> &mut Vec<u32> {
+        &mut self.items
+    }
+}
+
+impl Counter {
+    #[contract(requires(step > 0), ensures(|ret| *ret >= step))]
+    fn advance(&mut self, step: u32) -> u32 {
+        self.count += step;
+        self.count
+    }
+
+    #[contract(requires(self.count > 0))]
+    fn into_count(self) -> u32 {
+        self.count
+    }
+}
+
+mod requires {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_satisfied_preconditions() {
+        assert_eq!(divide(42, 2), 21);
+        assert_eq!(greet("alice"), 5);
+        assert_eq!(increment(41), 42);
+        assert_eq!(Counter { count: 1 }.into_count(), 1);
+    }
+
+    #[test]
+    fn it_succeeds_on_const_function() {
+        assert_eq!(HALF, 21);
+        assert_eq!(const_divide(9, 3), 3);
+    }
+
+    #[test]
+    #[cfg(all(
+        any(debug_assertions, feature = "always-check"),
+        not(feature = "passthrough")
+    ))]
+    #[should_panic(expected = "Precondition \"divisor != 0\" of const_divide violated")]
+    fn it_fails_on_const_function_only_if_checked() {
+        let divisor = 0;
+        let _ = const_divide(42, divisor);
+        //      ^-- should panic here only if checked
+    }
+
+    #[test]
+    #[cfg(all(
+        any(debug_assertions, feature = "always-check"),
+        not(feature = "passthrough")
+    ))]
+    #[should_panic(expected = "Precondition \"! ptr.is_null()\" of read_positive violated")]
+    fn it_fails_on_unsafe_function_only_if_checked() {
+        let _ = unsafe { read_positive(core::ptr::null()) };
+        //               ^-- should panic here only if checked
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Precondition \"! name.is_empty()\" of greet violated")
+    )]
+    fn it_fails_on_violated_precondition() {
+        let x = greet("");
+        //      ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Precondition \"name.len() <= 8\" of greet violated")
+    )]
+    fn it_fails_on_second_condition() {
+        let x = greet("bartholomew");
+        //      ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, 11);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Precondition \"self.count > 0\" of into_count violated")
+    )]
+    fn it_fails_on_method() {
+        let x = Counter { count: 0 }.into_count();
+        //                           ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, 0);
+    }
+}
+
+mod ensures {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_satisfied_postconditions() {
+        assert_eq!(parse("0"), Ok(1));
+        assert_eq!(parse("42"), Ok(42));
+        assert_eq!(wrongly_ensured(4), 8);
+
+        let mut counter = Counter { count: 0 };
+        assert_eq!(counter.advance(2), 2);
+        assert_eq!(counter.advance(3), 5);
+    }
+
+    #[test]
+    fn it_succeeds_on_early_return_and_question_mark_on_option() {
+        assert_eq!(first_positive(&[3, 0]), Some(3));
+        assert_eq!(first_positive(&[0, 3]), None);
+        assert_eq!(first_positive(&[]), None);
+        assert_eq!(clamp_to_ten(42), 10);
+        assert_eq!(clamp_to_ten(4), 4);
+        assert_eq!(singleton(4), [4]);
+        log_nothing(true);
+        log_nothing(false);
+    }
+
+    #[test]
+    fn it_succeeds_on_mutable_reference_to_self() {
+        let mut buffer = Buffer { items: vec![1, 2] };
+        buffer.items_mut().push(3);
+        assert_eq!(buffer.items, [1, 2, 3]);
+    }
+
+    #[test]
+    fn it_succeeds_on_impl_trait() {
+        assert_eq!(evens(3).collect::<Vec<_>>(), [0, 2, 4]);
+    }
+
+    #[test]
+    fn it_succeeds_on_unsafe_function() {
+        let x = 42;
+        assert_eq!(unsafe { read_positive(&x) }, 42);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Postcondition \"| ret | * ret > 0\" of read_positive violated")
+    )]
+    fn it_fails_on_unsafe_function() {
+        let x = 0;
+        let x = unsafe { read_positive(&x) };
+        //               ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "of parse violated")
+    )]
+    fn it_fails_on_early_return() {
+        let x = parse("foo");
+        //      ^-- should panic here only in debug builds
+
+        // for release builds
+        assert!(x.is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "of wrongly_ensured violated")
+    )]
+    fn it_fails_on_violated_postcondition() {
+        let x = wrongly_ensured(5);
+        //      ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, 10);
+    }
+}
@@ -0,0 +1,334 @@
+#![cfg(feature = "derive")]
+
+use chain_assertions::prelude::*;
+use chain_assertions::{ChainAssertVariants, Invariant, InvariantViolation};
+
+#[derive(ChainAssertVariants, Clone, Debug, PartialEq)]
+enum PlayerState {
+    Alive(u32),
+    Dead,
+    Spectating { team: u8, since: u64 },
+    WaitingForHTTPResponse(u16, &'static str),
+}
+
+#[derive(ChainAssertVariants, Debug, PartialEq)]
+enum Slot<T> {
+    Empty,
+    Filled(T),
+}
+
+#[derive(ChainAssertVariants, Debug, PartialEq)]
+enum Either<F, G> {
+    Left(F),
+    Right(G),
+}
+
+#[allow(non_camel_case_types)]
+#[derive(ChainAssertVariants, Debug, PartialEq)]
+enum Keyword {
+    r#type,
+    r#match(u8),
+}
+
+mod assert_is {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_expected_variant() {
+        let x = PlayerState::Alive(100).assert_is_alive();
+        assert_eq!(x, PlayerState::Alive(100));
+
+        let x = PlayerState::Dead.assert_is_dead();
+        assert_eq!(x, PlayerState::Dead);
+    }
+
+    #[test]
+    fn it_succeeds_on_generic_enum() {
+        let x = Slot::Filled(42).assert_is_filled();
+        assert_eq!(x, Slot::Filled(42));
+
+        let x = Slot::<i32>::Empty.assert_is_empty();
+        assert_eq!(x, Slot::Empty);
+    }
+
+    #[test]
+    fn it_succeeds_on_raw_identifier() {
+        let x = Keyword::r#type.assert_is_type();
+        assert_eq!(x, Keyword::r#type);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected match(..), got type")]
+    fn it_fails_on_raw_identifier() {
+        let _ = Keyword::r#type.assert_is_match();
+        //                      ^-- should panic here
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Alive(..), got Dead")]
+    fn it_fails_on_unit_variant() {
+        let _ = PlayerState::Dead.assert_is_alive();
+        //                        ^-- should panic here
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Spectating { .. }, got Alive(3)")]
+    fn it_fails_on_struct_pattern() {
+        let _ = PlayerState::Alive(3).assert_is_spectating();
+        //                            ^-- should panic here
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Empty, got Filled(\"x\")")]
+    fn it_fails_on_generic_enum() {
+        let _ = Slot::Filled("x").assert_is_empty();
+        //                        ^-- should panic here
+    }
+}
+
+mod debug_assert_is {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_expected_variant() {
+        let x = PlayerState::Alive(100).debug_assert_is_alive();
+        assert_eq!(x, PlayerState::Alive(100));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Expected WaitingForHTTPResponse(..), got Dead")
+    )]
+    fn it_fails_on_other_variant() {
+        let x = PlayerState::Dead.debug_assert_is_waiting_for_http_response();
+        //                        ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, PlayerState::Dead);
+    }
+}
+
+mod assert_is_and {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_single_field() {
+        let x = PlayerState::Alive(100).assert_is_alive_and(|hp| *hp > 0);
+        assert_eq!(x, PlayerState::Alive(100));
+    }
+
+    #[test]
+    fn it_succeeds_on_named_fields() {
+        let x = PlayerState::Spectating { team: 1, since: 30 }
+            .assert_is_spectating_and(|(team, since)| *team == 1 && *since < 60);
+        assert_eq!(x, PlayerState::Spectating { team: 1, since: 30 });
+    }
+
+    #[test]
+    fn it_succeeds_on_unnamed_fields() {
+        let x = PlayerState::WaitingForHTTPResponse(200, "OK")
+            .assert_is_waiting_for_http_response_and(|(status, _)| *status == 200);
+        assert_eq!(x, PlayerState::WaitingForHTTPResponse(200, "OK"));
+    }
+
+    #[test]
+    fn it_succeeds_on_enum_with_generic_named_f() {
+        let x = Either::<u8, &str>::Left(1).assert_is_left_and(|x| *x == 1);
+        assert_eq!(x, Either::Left(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Condition not satisfied for Right(\"\")")]
+    fn it_fails_on_enum_with_generic_named_f() {
+        let _ = Either::<u8, &str>::Right("").assert_is_right_and(|x| !x.is_empty());
+        //                                    ^-- should panic here
+    }
+
+    #[test]
+    fn it_succeeds_on_raw_identifier() {
+        let x = Keyword::r#match(1).assert_is_match_and(|x| *x == 1);
+        assert_eq!(x, Keyword::r#match(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Condition not satisfied for Alive(0)")]
+    fn it_fails_on_condition_not_satisfied() {
+        let _ = PlayerState::Alive(0).assert_is_alive_and(|hp| *hp > 0);
+        //                            ^-- should panic here
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Alive(..), got Spectating { team: 1, since: 30 }")]
+    fn it_fails_on_other_variant() {
+        let _ = PlayerState::Spectating { team: 1, since: 30 }.assert_is_alive_and(|_| true);
+        //                                                     ^-- should panic here
+    }
+}
+
+mod debug_assert_is_and {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_satisfied_variant() {
+        let x = Slot::Filled(42).debug_assert_is_filled_and(|x| *x == 42);
+        assert_eq!(x, Slot::Filled(42));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Condition not satisfied for Filled(41)")
+    )]
+    fn it_fails_on_condition_not_satisfied() {
+        let x = Slot::Filled(41).debug_assert_is_filled_and(|x| *x == 42);
+        //                       ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, Slot::Filled(41));
+    }
+}
+
+#[derive(Invariant, Debug, PartialEq)]
+#[invariant(self.hp <= self.max_hp)]
+#[invariant(self.max_hp > 0)]
+struct Player {
+    hp: u32,
+    max_hp: u32,
+}
+
+#[derive(Invariant, Debug, PartialEq)]
+struct Party {
+    #[invariant]
+    leader: Player,
+    size: usize,
+}
+
+#[derive(Invariant, Debug, PartialEq)]
+#[invariant(self.0.len() <= N)]
+struct Bounded<T, const N: usize>(Vec<T>);
+
+#[derive(Invariant, Debug, PartialEq)]
+#[invariant(!matches!(self, Health::Alive(0)))]
+enum Health {
+    Alive(u32),
+    Dead,
+}
+
+mod check_invariants {
+    use super::*;
+
+    #[test]
+    fn it_returns_ok_on_valid_value() {
+        let x = Player {
+            hp: 80,
+            max_hp: 100,
+        };
+        assert_eq!(x.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn it_returns_first_violated_condition() {
+        let x = Player { hp: 1, max_hp: 0 };
+        assert_eq!(
+            x.check_invariants(),
+            Err(InvariantViolation::new("self.hp <= self.max_hp"))
+        );
+
+        let x = Player { hp: 0, max_hp: 0 };
+        assert_eq!(
+            x.check_invariants(),
+            Err(InvariantViolation::new("self.max_hp > 0"))
+        );
+    }
+
+    #[test]
+    fn it_checks_fields() {
+        let x = Party {
+            leader: Player {
+                hp: 120,
+                max_hp: 100,
+            },
+            size: 4,
+        };
+        assert_eq!(x.size, 4);
+        assert_eq!(
+            x.check_invariants(),
+            Err(InvariantViolation::new("self.hp <= self.max_hp"))
+        );
+    }
+
+    #[test]
+    fn it_supports_generics() {
+        let x: Bounded<i32, 2> = Bounded(vec![1, 2, 3]);
+        assert_eq!(
+            x.check_invariants(),
+            Err(InvariantViolation::new("self.0.len() <= N"))
+        );
+    }
+
+    #[test]
+    fn it_supports_enums() {
+        assert_eq!(Health::Alive(1).check_invariants(), Ok(()));
+        assert_eq!(Health::Dead.check_invariants(), Ok(()));
+        assert!(Health::Alive(0).check_invariants().is_err());
+    }
+}
+
+mod assert_invariants {
+    use super::*;
+
+    #[test]
+    fn it_succeeds_on_valid_value() {
+        let x = Player {
+            hp: 80,
+            max_hp: 100,
+        }
+        .assert_invariants()
+        .hp;
+        assert_eq!(x, 80);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invariant \"self.hp <= self.max_hp\" violated by \
+                               Party { leader: Player { hp: 120, max_hp: 100 }, size: 1 }")]
+    fn it_fails_on_field_violation() {
+        let _ = Party {
+            leader: Player {
+                hp: 120,
+                max_hp: 100,
+            },
+            size: 1,
+        }
+        .assert_invariants();
+        // ^-- should panic here
+    }
+}
+
+mod assert_ok_invariants {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            any(debug_assertions, feature = "always-check"),
+            not(feature = "passthrough")
+        ),
+        should_panic(expected = "Invariant \"self.max_hp > 0\" violated by \
+                                 Ok(Player { hp: 0, max_hp: 0 })")
+    )]
+    fn it_fails_on_violation() {
+        let x: Result<Player, &str> = Ok(Player { hp: 0, max_hp: 0 });
+        let x = x.debug_assert_ok_invariants();
+        //        ^-- should panic here only in debug builds
+
+        // for release builds
+        assert_eq!(x, Ok(Player { hp: 0, max_hp: 0 }));
+    }
+}